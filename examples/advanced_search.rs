@@ -27,7 +27,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         println!("  {}. Ticket #{:?}: {}", i + 1, ticket.id, ticket.subject);
                     }
                     SearchResult::User(user) => {
-                        println!("  {}. User: {} ({})", i + 1, user.name, user.email);
+                        println!(
+                            "  {}. User: {} ({})",
+                            i + 1,
+                            user.name,
+                            user.email.as_deref().unwrap_or("-")
+                        );
                     }
                     SearchResult::Organization(org) => {
                         println!("  {}. Organization: {}", i + 1, org.name);
@@ -140,7 +145,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             );
             for result in response.results.iter().take(5) {
                 if let SearchResult::User(user) = result {
-                    println!("  - {}: {}", user.name, user.email);
+                    println!(
+                        "  - {}: {}",
+                        user.name,
+                        user.email.as_deref().unwrap_or("-")
+                    );
                 }
             }
         }
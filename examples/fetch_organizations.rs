@@ -26,10 +26,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             } else {
                 for (i, org) in organizations.iter().take(5).enumerate() {
                     println!("   {}. {} (ID: {})", i + 1, org.name, org.id.unwrap_or(0));
-                    if let Some(domain_names) = &org.domain_names {
-                        if !domain_names.is_empty() {
-                            println!("      Domains: {:?}", domain_names);
-                        }
+                    if let Some(domain_names) = &org.domain_names
+                        && !domain_names.is_empty()
+                    {
+                        println!("      Domains: {:?}", domain_names);
                     }
                 }
                 if organizations.len() > 5 {
@@ -37,63 +37,63 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
 
                 // Get detailed info for first organization
-                if let Some(first_org) = organizations.first() {
-                    if let Some(org_id) = first_org.id {
-                        println!(
-                            "\n2. Fetching detailed info for organization ID {}...",
-                            org_id
-                        );
-                        match client.get_organization(org_id).await {
-                            Ok(org_detail) => {
-                                println!("Organization Details:");
-                                println!("   Name: {}", org_detail.name);
-                                if let Some(details) = &org_detail.details {
-                                    println!("   Details: {}", details);
-                                }
-                                if let Some(notes) = &org_detail.notes {
-                                    let short_notes = if notes.len() > 100 {
-                                        format!("{}...", &notes[..100])
-                                    } else {
-                                        notes.clone()
-                                    };
-                                    println!("   Notes: {}", short_notes);
-                                }
-                                if let Some(domain_names) = &org_detail.domain_names {
-                                    println!("   Domain Names: {:?}", domain_names);
-                                }
-                                if let Some(tags) = &org_detail.tags {
-                                    println!("   Tags: {:?}", tags);
-                                }
-                                if let Some(created_at) = &org_detail.created_at {
-                                    println!("   Created: {}", created_at);
-                                }
-                                if let Some(updated_at) = &org_detail.updated_at {
-                                    println!("   Updated: {}", updated_at);
-                                }
+                if let Some(first_org) = organizations.first()
+                    && let Some(org_id) = first_org.id
+                {
+                    println!(
+                        "\n2. Fetching detailed info for organization ID {}...",
+                        org_id
+                    );
+                    match client.get_organization(org_id).await {
+                        Ok(org_detail) => {
+                            println!("Organization Details:");
+                            println!("   Name: {}", org_detail.name);
+                            if let Some(details) = &org_detail.details {
+                                println!("   Details: {}", details);
+                            }
+                            if let Some(notes) = &org_detail.notes {
+                                let short_notes = if notes.len() > 100 {
+                                    format!("{}...", &notes[..100])
+                                } else {
+                                    notes.clone()
+                                };
+                                println!("   Notes: {}", short_notes);
+                            }
+                            if let Some(domain_names) = &org_detail.domain_names {
+                                println!("   Domain Names: {:?}", domain_names);
+                            }
+                            if let Some(tags) = &org_detail.tags {
+                                println!("   Tags: {:?}", tags);
+                            }
+                            if let Some(created_at) = &org_detail.created_at {
+                                println!("   Created: {}", created_at);
+                            }
+                            if let Some(updated_at) = &org_detail.updated_at {
+                                println!("   Updated: {}", updated_at);
                             }
-                            Err(e) => println!("Failed to fetch organization details: {}", e),
                         }
+                        Err(e) => println!("Failed to fetch organization details: {}", e),
+                    }
 
-                        // 3. Get users in this organization
-                        println!("\n3. Fetching users in organization {}...", org_id);
-                        match client.list_users_in_organization(org_id).await {
-                            Ok(org_users) => {
-                                println!("Found {} users in this organization", org_users.len());
-                                for (i, user) in org_users.iter().take(5).enumerate() {
-                                    println!(
-                                        "   {}. {} <{}> (Role: {:?})",
-                                        i + 1,
-                                        user.name,
-                                        &user.email,
-                                        user.role
-                                    );
-                                }
-                                if org_users.len() > 5 {
-                                    println!("   ... and {} more users", org_users.len() - 5);
-                                }
+                    // 3. Get users in this organization
+                    println!("\n3. Fetching users in organization {}...", org_id);
+                    match client.list_users_in_organization(org_id).await {
+                        Ok(org_users) => {
+                            println!("Found {} users in this organization", org_users.len());
+                            for (i, user) in org_users.iter().take(5).enumerate() {
+                                println!(
+                                    "   {}. {} <{}> (Role: {:?})",
+                                    i + 1,
+                                    user.name,
+                                    user.email.as_deref().unwrap_or("-"),
+                                    user.role
+                                );
+                            }
+                            if org_users.len() > 5 {
+                                println!("   ... and {} more users", org_users.len() - 5);
                             }
-                            Err(e) => println!("Failed to fetch organization users: {}", e),
                         }
+                        Err(e) => println!("Failed to fetch organization users: {}", e),
                     }
                 }
             }
@@ -150,14 +150,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             // Count organizations with domains
             let orgs_with_domains = orgs
                 .iter()
-                .filter(|org| org.domain_names.as_ref().map_or(false, |d| !d.is_empty()))
+                .filter(|org| org.domain_names.as_ref().is_some_and(|d| !d.is_empty()))
                 .count();
             println!("   Organizations with domains: {}", orgs_with_domains);
 
             // Count organizations with tags
             let orgs_with_tags = orgs
                 .iter()
-                .filter(|org| org.tags.as_ref().map_or(false, |t| !t.is_empty()))
+                .filter(|org| org.tags.as_ref().is_some_and(|t| !t.is_empty()))
                 .count();
             println!("   Organizations with tags: {}", orgs_with_tags);
 
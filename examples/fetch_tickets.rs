@@ -34,29 +34,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             // Store first ticket ID for further examples
-            if let Some(first_ticket) = tickets.first() {
-                if let Some(ticket_id) = first_ticket.id {
-                    println!("\n2. Fetching detailed info for ticket ID {}...", ticket_id);
-                    match client.get_ticket(ticket_id).await {
-                        Ok(ticket_detail) => {
-                            println!("Ticket Details:");
-                            println!("   Subject: {}", ticket_detail.subject);
-                            if let Some(desc) = &ticket_detail.description {
-                                let short_desc = if desc.len() > 100 {
-                                    format!("{}...", &desc[..100])
-                                } else {
-                                    desc.clone()
-                                };
-                                println!("   Description: {}", short_desc);
-                            }
-                            println!("   Status: {:?}", ticket_detail.status);
-                            println!("   Priority: {:?}", ticket_detail.priority);
-                            if let Some(tags) = &ticket_detail.tags {
-                                println!("   Tags: {:?}", tags);
-                            }
+            if let Some(first_ticket) = tickets.first()
+                && let Some(ticket_id) = first_ticket.id
+            {
+                println!("\n2. Fetching detailed info for ticket ID {}...", ticket_id);
+                match client.get_ticket(ticket_id).await {
+                    Ok(ticket_detail) => {
+                        println!("Ticket Details:");
+                        println!("   Subject: {}", ticket_detail.subject);
+                        if let Some(desc) = &ticket_detail.description {
+                            let short_desc = if desc.len() > 100 {
+                                format!("{}...", &desc[..100])
+                            } else {
+                                desc.clone()
+                            };
+                            println!("   Description: {}", short_desc);
+                        }
+                        println!("   Status: {:?}", ticket_detail.status);
+                        println!("   Priority: {:?}", ticket_detail.priority);
+                        if let Some(tags) = &ticket_detail.tags {
+                            println!("   Tags: {:?}", tags);
                         }
-                        Err(e) => println!("Failed to fetch ticket details: {}", e),
                     }
+                    Err(e) => println!("Failed to fetch ticket details: {}", e),
                 }
             }
         }
@@ -51,7 +51,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     "   {}. {} <{}> (ID: {}, Role: {:?})",
                     i + 1,
                     user.name,
-                    &user.email,
+                    user.email.as_deref().unwrap_or("-"),
                     user.id.unwrap_or(0),
                     user.role
                 );
@@ -61,31 +61,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             // Store first user ID for detailed lookup
-            if let Some(first_user) = users.first() {
-                if let Some(user_id) = first_user.id {
-                    println!("\n3. Fetching detailed info for user ID {}...", user_id);
-                    match client.get_user(user_id).await {
-                        Ok(user_detail) => {
-                            println!("User Details:");
-                            println!("   Name: {}", user_detail.name);
-                            println!("   Email: {}", user_detail.email);
-                            println!("   Role: {:?}", user_detail.role);
-                            if let Some(phone) = &user_detail.phone {
-                                println!("   Phone: {}", phone);
-                            }
-                            if let Some(timezone) = &user_detail.time_zone {
-                                println!("   Timezone: {}", timezone);
-                            }
-                            if let Some(locale) = &user_detail.locale {
-                                println!("   Locale: {}", locale);
-                            }
-                            println!("   Active: {}", user_detail.active.unwrap_or(false));
-                            if let Some(tags) = &user_detail.tags {
-                                println!("   Tags: {:?}", tags);
-                            }
+            if let Some(first_user) = users.first()
+                && let Some(user_id) = first_user.id
+            {
+                println!("\n3. Fetching detailed info for user ID {}...", user_id);
+                match client.get_user(user_id).await {
+                    Ok(user_detail) => {
+                        println!("User Details:");
+                        println!("   Name: {}", user_detail.name);
+                        println!("   Email: {}", user_detail.email.as_deref().unwrap_or("-"));
+                        println!("   Role: {:?}", user_detail.role);
+                        if let Some(phone) = &user_detail.phone {
+                            println!("   Phone: {}", phone);
+                        }
+                        if let Some(timezone) = &user_detail.time_zone {
+                            println!("   Timezone: {}", timezone);
+                        }
+                        if let Some(locale) = &user_detail.locale {
+                            println!("   Locale: {}", locale);
+                        }
+                        println!("   Active: {}", user_detail.active.unwrap_or(false));
+                        if let Some(tags) = &user_detail.tags {
+                            println!("   Tags: {:?}", tags);
                         }
-                        Err(e) => println!("Failed to fetch user details: {}", e),
                     }
+                    Err(e) => println!("Failed to fetch user details: {}", e),
                 }
             }
         }
@@ -105,7 +105,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             for user in search_results.iter().take(3) {
                 println!(
                     "   - {} <{}> (Role: {:?})",
-                    user.name, &user.email, user.role
+                    user.name,
+                    user.email.as_deref().unwrap_or("-"),
+                    user.role
                 );
             }
         }
@@ -149,7 +151,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     org_id
                                 );
                                 for user in org_users.iter().take(3) {
-                                    println!("   - {} <{}>", user.name, &user.email);
+                                    println!(
+                                        "   - {} <{}>",
+                                        user.name,
+                                        user.email.as_deref().unwrap_or("-")
+                                    );
                                 }
                             }
                             Err(e) => println!("Failed to fetch organization users: {}", e),
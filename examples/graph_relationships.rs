@@ -84,7 +84,11 @@ async fn example_sideloading(client: &ZendeskClient) -> Result<(), Box<dyn std::
                 if let Some(requester) =
                     tickets_with_sideloading.find_sideloaded::<User>("users", requester_id)
                 {
-                    println!("     Requester: {} ({})", requester.name, requester.email);
+                    println!(
+                        "     Requester: {} ({})",
+                        requester.name,
+                        requester.email.as_deref().unwrap_or("-")
+                    );
                 }
             }
 
@@ -232,30 +236,29 @@ async fn example_graph_traversal(client: &ZendeskClient) -> Result<(), Box<dyn s
     println!("\nReverse lookup: Finding users related to tickets...");
 
     // Get a sample ticket first
-    if let Ok(tickets) = client.list_tickets().await {
-        if let Some(sample_ticket) = tickets.first() {
-            if let Some(ticket_id) = sample_ticket.id {
-                for field in &ticket_fields {
-                    if field.relationship_target_type == ZendeskObjectType::User {
-                        match client
-                            .get_users_related_to_ticket(ticket_id, field.id)
-                            .await
-                        {
-                            Ok(related_users) => {
-                                if !related_users.results.is_empty() {
-                                    println!(
-                                        "  Ticket {} has {} related users via '{}'",
-                                        ticket_id,
-                                        related_users.results.len(),
-                                        field.title
-                                    );
-                                }
-                            }
-                            Err(_) => {
-                                // Field might not have any relationships yet
-                            }
+    if let Ok(tickets) = client.list_tickets().await
+        && let Some(sample_ticket) = tickets.first()
+        && let Some(ticket_id) = sample_ticket.id
+    {
+        for field in &ticket_fields {
+            if field.relationship_target_type == ZendeskObjectType::User {
+                match client
+                    .get_users_related_to_ticket(ticket_id, field.id)
+                    .await
+                {
+                    Ok(related_users) => {
+                        if !related_users.results.is_empty() {
+                            println!(
+                                "  Ticket {} has {} related users via '{}'",
+                                ticket_id,
+                                related_users.results.len(),
+                                field.title
+                            );
                         }
                     }
+                    Err(_) => {
+                        // Field might not have any relationships yet
+                    }
                 }
             }
         }
@@ -314,7 +317,7 @@ async fn example_advanced_graph_analysis(
 
     // Find power users (users with most tickets)
     let mut user_counts: Vec<_> = user_ticket_count.into_iter().collect();
-    user_counts.sort_by(|a, b| b.1.cmp(&a.1));
+    user_counts.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
     println!("\nTop 5 Users by Ticket Count:");
     for (user_id, count) in user_counts.iter().take(5) {
@@ -324,7 +327,7 @@ async fn example_advanced_graph_analysis(
 
     // Find organizations with most tickets
     let mut org_counts: Vec<_> = org_ticket_count.into_iter().collect();
-    org_counts.sort_by(|a, b| b.1.cmp(&a.1));
+    org_counts.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
     println!("\nTop 5 Organizations by Ticket Count:");
     for (org_id, count) in org_counts.iter().take(5) {
@@ -42,7 +42,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Successfully created user!");
             println!("   ID: {}", created_user.id.unwrap_or(0));
             println!("   Name: {}", created_user.name);
-            println!("   Email: {}", created_user.email);
+            println!("   Email: {}", created_user.email.as_deref().unwrap_or("-"));
             println!("   Role: {:?}", created_user.role);
             if let Some(phone) = &created_user.phone {
                 println!("   Phone: {}", phone);
@@ -83,7 +83,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Successfully updated user!");
             println!("   ID: {}", updated_user.id.unwrap_or(0));
             println!("   Name: {} (updated)", updated_user.name);
-            println!("   Email: {}", updated_user.email);
+            println!("   Email: {}", updated_user.email.as_deref().unwrap_or("-"));
             if let Some(phone) = &updated_user.phone {
                 println!("   Phone: {} (updated)", phone);
             }
@@ -106,7 +106,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Successfully fetched user details:");
             println!("   ID: {}", fetched_user.id.unwrap_or(0));
             println!("   Name: {}", fetched_user.name);
-            println!("   Email: {}", fetched_user.email);
+            println!("   Email: {}", fetched_user.email.as_deref().unwrap_or("-"));
             println!("   Role: {:?}", fetched_user.role);
             println!("   Active: {}", fetched_user.active.unwrap_or(false));
             println!("   Verified: {}", fetched_user.verified.unwrap_or(false));
@@ -149,7 +149,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Found user by email search:");
             println!("   ID: {}", found_user.id.unwrap_or(0));
             println!("   Name: {}", found_user.name);
-            println!("   Email: {}", found_user.email);
+            println!("   Email: {}", found_user.email.as_deref().unwrap_or("-"));
             println!("   Role: {:?}", found_user.role);
         }
         Err(e) => {
@@ -178,7 +178,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Successfully created second user!");
             println!("   ID: {}", created_user.id.unwrap_or(0));
             println!("   Name: {}", created_user.name);
-            println!("   Email: {}", created_user.email);
+            println!("   Email: {}", created_user.email.as_deref().unwrap_or("-"));
             created_user.id.unwrap_or(0)
         }
         Err(e) => {
@@ -193,7 +193,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(demo_users) => {
             let api_demo_users: Vec<_> = demo_users
                 .iter()
-                .filter(|user| user.name.contains("Demo") || user.email.contains("demo"))
+                .filter(|user| {
+                    user.name.contains("Demo")
+                        || user
+                            .email
+                            .as_deref()
+                            .is_some_and(|email| email.contains("demo"))
+                })
                 .collect();
 
             println!("Found {} demo users:", api_demo_users.len());
@@ -202,7 +208,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     "   {}. {} <{}> (ID: {}, Role: {:?})",
                     i + 1,
                     user.name,
-                    user.email,
+                    user.email.as_deref().unwrap_or("-"),
                     user.id.unwrap_or(0),
                     user.role
                 );
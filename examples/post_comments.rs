@@ -86,6 +86,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     tags: None,
                     additional_tags: None,
                     remove_tags: None,
+                    email_ccs: None,
+                    followers: None,
                 },
             },
         )
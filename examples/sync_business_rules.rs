@@ -0,0 +1,44 @@
+use std::env;
+use zendesk_api_rust::auth::AuthMethod;
+use zendesk_api_rust::sync::DesiredState;
+use zendesk_api_rust::{ZendeskClient, ZendeskConfig};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Load configuration from environment variables
+    let subdomain = env::var("ZENDESK_SUBDOMAIN").expect("ZENDESK_SUBDOMAIN must be set");
+    let email = env::var("ZENDESK_EMAIL").expect("ZENDESK_EMAIL must be set");
+    let token = env::var("ZENDESK_API_TOKEN").expect("ZENDESK_API_TOKEN must be set");
+
+    let auth = AuthMethod::api_token(&email, &token);
+    let config = ZendeskConfig::new(&subdomain, auth);
+    let client = ZendeskClient::new(config)?;
+
+    // Everything except `--apply` is a rules file
+    let args: Vec<String> = env::args().skip(1).collect();
+    let apply = args.iter().any(|arg| arg == "--apply");
+    let paths: Vec<&String> = args.iter().filter(|arg| *arg != "--apply").collect();
+    if paths.is_empty() {
+        eprintln!("Usage: sync_business_rules <rules.json|rules.yaml>... [--apply]");
+        std::process::exit(2);
+    }
+
+    let desired = DesiredState::from_files(&paths)?;
+    let plan = client.plan_sync(&desired).await?;
+    print!("{}", plan);
+
+    if plan.is_empty() || !apply {
+        if !plan.is_empty() {
+            println!("\nRun again with --apply to make these changes.");
+        }
+        return Ok(());
+    }
+
+    let report = client.apply_sync_plan(&plan).await?;
+    println!(
+        "\nApplied: {} created, {} updated, {} deleted.",
+        report.created, report.updated, report.deleted
+    );
+
+    Ok(())
+}
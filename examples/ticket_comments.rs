@@ -38,12 +38,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
 
                 // Show attachments if any
-                if let Some(attachments) = &comment.attachments {
-                    if !attachments.is_empty() {
-                        println!("Attachments:");
-                        for attachment in attachments {
-                            println!("  - {} ({} bytes)", attachment.file_name, attachment.size);
-                        }
+                if let Some(attachments) = &comment.attachments
+                    && !attachments.is_empty()
+                {
+                    println!("Attachments:");
+                    for attachment in attachments {
+                        println!("  - {} ({} bytes)", attachment.file_name, attachment.size);
                     }
                 }
             }
@@ -222,7 +222,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             .comment(
                                 "Bulk update: Adding standardized tag to all API test tickets.",
                             )
-                            .priority(ticket.priority.clone().unwrap_or(TicketPriority::Normal))
+                            .priority(ticket.priority.unwrap_or(TicketPriority::Normal))
                             .ticket_type(ticket.ticket_type.clone().unwrap_or(TicketType::Question))
                             .status(ticket.status.clone().unwrap_or(TicketStatus::Open))
                             .requester_id(ticket.requester_id.unwrap_or(current_user_id))
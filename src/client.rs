@@ -164,6 +164,18 @@ impl ZendeskClient {
         self.handle_response(response).await
     }
 
+    /// DELETE an endpoint that answers 204 No Content; any response body is ignored
+    pub async fn delete_no_content(&self, endpoint: &str) -> Result<()> {
+        let response = self
+            .request(Method::DELETE, endpoint, Option::<&()>::None, None)
+            .await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(response_error(response).await)
+        }
+    }
+
     pub async fn delete_with_params<T>(&self, endpoint: &str, params: &QueryParams) -> Result<T>
     where
         T: DeserializeOwned,
@@ -187,26 +199,115 @@ impl ZendeskClient {
         let url = self.build_url_with_params(endpoint, params)?;
         let mut request = self.http_client.request(method, url);
 
-        request = self.add_headers(request)?;
+        request = self.add_headers(request, "application/json")?;
 
         if let Some(body) = body {
             // Debug output for user creation requests
-            if std::env::var("ZENDESK_DEBUG").is_ok() {
-                if let Ok(json_str) = serde_json::to_string_pretty(body) {
-                    println!("Debug - Request body: {}", json_str);
-                }
+            if std::env::var("ZENDESK_DEBUG").is_ok()
+                && let Ok(json_str) = serde_json::to_string_pretty(body)
+            {
+                println!("Debug - Request body: {}", json_str);
             }
             request = request.json(body);
         }
 
-        let response = request.send().await.map_err(ZendeskError::from)?;
+        self.send_with_retry(request).await
+    }
 
-        Ok(response)
+    /// POST a raw binary body (e.g. a file upload) and parse the JSON response
+    pub async fn post_bytes<T>(
+        &self,
+        endpoint: &str,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.build_url_with_params(endpoint, None)?;
+        let request = self
+            .add_headers(self.http_client.post(url), content_type)?
+            .body(body);
+        let response = self.send_with_retry(request).await?;
+        self.handle_response(response).await
     }
 
+    /// GET an absolute URL and return the raw response for streaming its body.
+    /// Credentials are only attached when the URL points at this account's host.
+    pub async fn get_raw(&self, url: &str) -> Result<Response> {
+        let url = Url::parse(url).map_err(ZendeskError::from)?;
+        let mut request = self.http_client.get(url.clone());
+
+        if self.is_account_url(&url) {
+            request = request.header("Authorization", self.config.auth.to_header_value());
+        }
+        if let Some(ref user_agent) = self.config.user_agent {
+            request = request.header("User-Agent", user_agent);
+        }
+
+        let response = self.send_with_retry(request).await?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let error_text = response.text().await.unwrap_or_default();
+            match status.as_u16() {
+                401 => Err(ZendeskError::auth(error_text)),
+                _ => Err(ZendeskError::api(status.as_u16(), error_text)),
+            }
+        }
+    }
+
+    /// Send a request, retrying up to `max_retries` times when Zendesk answers 429, or 503
+    /// for idempotent methods. A 429 means the request was rejected before processing; a 503
+    /// may come from a proxy after a write was applied, so POST and PATCH are not retried.
+    /// Waits follow `Retry-After` up to one minute; beyond that the response is returned.
+    async fn send_with_retry(&self, request: RequestBuilder) -> Result<Response> {
+        let mut attempt = 0;
+        loop {
+            let Some(attempt_request) = request.try_clone() else {
+                return request.send().await.map_err(ZendeskError::from);
+            };
+            let attempt_request = attempt_request.build().map_err(ZendeskError::from)?;
+            let method = attempt_request.method().clone();
+
+            let response = self
+                .http_client
+                .execute(attempt_request)
+                .await
+                .map_err(ZendeskError::from)?;
+            let status = response.status().as_u16();
+            if should_retry(&method, status) && attempt < self.config.max_retries {
+                // A longer Retry-After is left to the caller as a `RateLimit` error
+                if let Some(wait) = retry_wait(retry_after_seconds(&response), attempt) {
+                    tokio::time::sleep(Duration::from_secs(wait)).await;
+                    attempt += 1;
+                    continue;
+                }
+            }
+
+            return Ok(response);
+        }
+    }
+
+    fn is_account_url(&self, url: &Url) -> bool {
+        url.scheme() == self.base_url.scheme()
+            && url.host_str() == self.base_url.host_str()
+            && url.port_or_known_default() == self.base_url.port_or_known_default()
+    }
+
+    /// Resolve `endpoint` against the API base URL. Absolute URLs, such as `next_page`
+    /// links, are accepted only for this account's host so credentials never leave it.
     fn build_url_with_params(&self, endpoint: &str, params: Option<&QueryParams>) -> Result<Url> {
         let endpoint = endpoint.trim_start_matches('/');
         let mut url = self.base_url.join(endpoint).map_err(ZendeskError::from)?;
+        if !self.is_account_url(&url) {
+            return Err(ZendeskError::InvalidUrl(format!(
+                "{} is not on the account host {}",
+                url,
+                self.base_url.host_str().unwrap_or_default()
+            )));
+        }
 
         if let Some(params) = params {
             let query_string = params.to_query_string();
@@ -220,11 +321,11 @@ impl ZendeskClient {
         Ok(url)
     }
 
-    fn add_headers(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+    fn add_headers(&self, request: RequestBuilder, content_type: &str) -> Result<RequestBuilder> {
         let auth_header = self.config.auth.to_header_value();
         let mut request = request
             .header("Authorization", auth_header)
-            .header("Content-Type", "application/json")
+            .header("Content-Type", content_type)
             .header("Accept", "application/json");
 
         if let Some(ref user_agent) = self.config.user_agent {
@@ -238,53 +339,206 @@ impl ZendeskClient {
     where
         T: DeserializeOwned,
     {
-        let status = response.status();
-
-        if status.is_success() {
+        if response.status().is_success() {
             let json: T = response.json().await.map_err(ZendeskError::from)?;
             Ok(json)
         } else {
-            let error_text = response.text().await.unwrap_or_default();
+            Err(response_error(response).await)
+        }
+    }
+}
 
-            // Try to parse error as JSON to get more details
-            let error_message = if let Ok(error_json) = serde_json::from_str::<Value>(&error_text) {
-                // For 422 errors, try to get detailed validation errors
-                if status.as_u16() == 422 {
-                    if let Some(details) = error_json.get("details") {
-                        format!("RecordInvalid - Details: {}", details.to_string())
-                    } else if let Some(errors) = error_json.get("errors") {
-                        format!("RecordInvalid - Errors: {}", errors.to_string())
-                    } else if let Some(description) = error_json.get("description") {
-                        format!(
-                            "RecordInvalid - {}",
-                            description.as_str().unwrap_or("Unknown validation error")
-                        )
-                    } else {
-                        error_json
-                            .get("error")
-                            .and_then(|e| e.as_str())
-                            .unwrap_or(&error_text)
-                            .to_string()
-                    }
-                } else {
-                    error_json
-                        .get("error")
-                        .and_then(|e| e.as_str())
-                        .unwrap_or(&error_text)
-                        .to_string()
-                }
+/// Turn an unsuccessful response into the matching error, using Zendesk's error body
+async fn response_error(response: Response) -> ZendeskError {
+    let status = response.status();
+    let retry_after = retry_after_seconds(&response);
+    let error_text = response.text().await.unwrap_or_default();
+
+    // Try to parse error as JSON to get more details
+    let error_message = if let Ok(error_json) = serde_json::from_str::<Value>(&error_text) {
+        // For 422 errors, try to get detailed validation errors
+        if status.as_u16() == 422 {
+            if let Some(details) = error_json.get("details") {
+                format!("RecordInvalid - Details: {}", details)
+            } else if let Some(errors) = error_json.get("errors") {
+                format!("RecordInvalid - Errors: {}", errors)
+            } else if let Some(description) = error_json.get("description") {
+                format!(
+                    "RecordInvalid - {}",
+                    description.as_str().unwrap_or("Unknown validation error")
+                )
             } else {
-                error_text
-            };
-
-            match status.as_u16() {
-                401 => Err(ZendeskError::auth(error_message)),
-                429 => {
-                    // TODO: Parse Retry-After header for rate limiting
-                    Err(ZendeskError::RateLimit { retry_after: None })
-                }
-                _ => Err(ZendeskError::api(status.as_u16(), error_message)),
+                error_json
+                    .get("error")
+                    .and_then(|e| e.as_str())
+                    .unwrap_or(&error_text)
+                    .to_string()
             }
+        } else {
+            error_json
+                .get("error")
+                .and_then(|e| e.as_str())
+                .unwrap_or(&error_text)
+                .to_string()
         }
+    } else {
+        error_text
+    };
+
+    match status.as_u16() {
+        401 => ZendeskError::auth(error_message),
+        429 => ZendeskError::RateLimit { retry_after },
+        _ => ZendeskError::api(status.as_u16(), error_message),
+    }
+}
+
+fn should_retry(method: &Method, status: u16) -> bool {
+    match status {
+        429 => true,
+        503 => matches!(*method, Method::GET | Method::PUT | Method::DELETE),
+        _ => false,
+    }
+}
+
+/// Longest wait, in seconds, before retrying a request
+const MAX_RETRY_WAIT: u64 = 60;
+
+/// Exponential backoff in seconds, capped at one minute
+fn retry_backoff(attempt: u32) -> u64 {
+    1u64.checked_shl(attempt)
+        .unwrap_or(u64::MAX)
+        .min(MAX_RETRY_WAIT)
+}
+
+/// Seconds to wait before the next attempt, or `None` when the server asks for longer
+/// than `MAX_RETRY_WAIT`
+fn retry_wait(retry_after: Option<u64>, attempt: u32) -> Option<u64> {
+    match retry_after {
+        Some(seconds) if seconds > MAX_RETRY_WAIT => None,
+        Some(seconds) => Some(seconds),
+        None => Some(retry_backoff(attempt)),
+    }
+}
+
+fn retry_after_seconds(response: &Response) -> Option<u64> {
+    response
+        .headers()
+        .get("Retry-After")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+}
+
+/// A client whose API root is a mock server started by the test
+#[cfg(test)]
+pub(crate) fn mock_client(server_uri: &str) -> ZendeskClient {
+    let config = ZendeskConfig::new(
+        "example",
+        crate::auth::AuthMethod::api_token("a@example.com", "t"),
+    )
+    .with_api_url(format!("{}/api/v2", server_uri))
+    .with_max_retries(0);
+    ZendeskClient::new(config).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthMethod;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_delete_no_content() {
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v2/uploads/abc.json"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v2/uploads/gone.json"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({ "error": "RecordNotFound" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        client.delete_upload("abc").await.unwrap();
+        let error = client.delete_upload("gone").await.unwrap_err();
+        assert!(matches!(error, ZendeskError::Api { status: 404, .. }));
+    }
+
+    #[test]
+    fn test_absolute_urls_must_stay_on_account_host() {
+        let config = ZendeskConfig::new("example", AuthMethod::api_token("a@example.com", "t"));
+        let client = ZendeskClient::new(config).unwrap();
+
+        let next_page = "https://example.zendesk.com/api/v2/users.json?page=2";
+        assert_eq!(
+            client
+                .build_url_with_params(next_page, None)
+                .unwrap()
+                .as_str(),
+            next_page
+        );
+        assert!(client.build_url_with_params("users.json", None).is_ok());
+        assert!(
+            client
+                .build_url_with_params("https://attacker.example/api/v2/users.json", None)
+                .is_err()
+        );
+        assert!(
+            client
+                .build_url_with_params("http://example.zendesk.com/api/v2/users.json", None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        assert!(should_retry(&Method::POST, 429));
+        assert!(!should_retry(&Method::POST, 503));
+        assert!(!should_retry(&Method::PATCH, 503));
+        assert!(should_retry(&Method::GET, 503));
+        assert!(should_retry(&Method::PUT, 503));
+        assert!(should_retry(&Method::DELETE, 503));
+        assert!(!should_retry(&Method::GET, 500));
+
+        assert_eq!(retry_backoff(0), 1);
+        assert_eq!(retry_backoff(3), 8);
+        assert_eq!(retry_backoff(6), 60);
+        assert_eq!(retry_backoff(64), 60);
+        assert_eq!(retry_backoff(u32::MAX), 60);
+
+        assert_eq!(retry_wait(Some(30), 0), Some(30));
+        assert_eq!(retry_wait(Some(60), 0), Some(60));
+        assert_eq!(retry_wait(Some(3600), 0), None);
+        assert_eq!(retry_wait(None, 2), Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_long_retry_after_is_returned_as_rate_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/users/1.json"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = ZendeskConfig::new("example", AuthMethod::api_token("a@example.com", "t"))
+            .with_api_url(format!("{}/api/v2", server.uri()))
+            .with_max_retries(3);
+        let client = ZendeskClient::new(config).unwrap();
+        let error = client.get_user(1).await.unwrap_err();
+        assert!(matches!(
+            error,
+            ZendeskError::RateLimit {
+                retry_after: Some(3600)
+            }
+        ));
     }
 }
@@ -22,6 +22,10 @@ pub struct ZendeskConfig {
     
     /// Custom user agent (optional)
     pub user_agent: Option<String>,
+    
+    /// API root used instead of the subdomain's, e.g. a proxy or a local mock server (optional)
+    #[serde(default)]
+    pub api_url: Option<String>,
 }
 
 impl ZendeskConfig {
@@ -33,6 +37,7 @@ impl ZendeskConfig {
             timeout_seconds: 30,
             max_retries: 3,
             user_agent: Some(format!("zendesk-api-rust/{}", env!("CARGO_PKG_VERSION"))),
+            api_url: None,
         }
     }
     
@@ -56,7 +61,16 @@ impl ZendeskConfig {
         self
     }
     
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = Some(api_url.into());
+        self
+    }
+    
     pub fn base_url(&self) -> Result<Url> {
+        if let Some(api_url) = &self.api_url {
+            let api_url = format!("{}/", api_url.trim_end_matches('/'));
+            return Url::parse(&api_url).map_err(ZendeskError::from);
+        }
         let url_str = format!("https://{}.zendesk.com/api/{}/", self.subdomain, self.api_version);
         Url::parse(&url_str).map_err(ZendeskError::from)
     }
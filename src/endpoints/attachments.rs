@@ -0,0 +1,146 @@
+use crate::client::ZendeskClient;
+use crate::errors::{Result, ZendeskError};
+use crate::models::attachment::{
+    AttachmentResponse, Upload, UploadResponse, content_type_for_filename,
+};
+use crate::models::ticket::CommentAttachment;
+use reqwest::Response;
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// A streaming attachment download
+#[derive(Debug)]
+pub struct AttachmentDownload {
+    response: Response,
+}
+
+impl AttachmentDownload {
+    /// `Content-Type` reported by the server
+    pub fn content_type(&self) -> Option<&str> {
+        self.response
+            .headers()
+            .get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+    }
+
+    /// Body size, when the server reports one
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Read the next chunk of the body, or `None` when it is exhausted
+    pub async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>> {
+        let chunk = self.response.chunk().await.map_err(ZendeskError::from)?;
+        Ok(chunk.map(|bytes| bytes.to_vec()))
+    }
+
+    /// Read the whole body into memory
+    pub async fn bytes(self) -> Result<Vec<u8>> {
+        let bytes = self.response.bytes().await.map_err(ZendeskError::from)?;
+        Ok(bytes.to_vec())
+    }
+
+    /// Copy the body into `writer` chunk by chunk, returning the number of bytes written
+    pub async fn write_to<W>(mut self, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut written = 0;
+        while let Some(chunk) = self.response.chunk().await.map_err(ZendeskError::from)? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+}
+
+impl ZendeskClient {
+    /// Upload file contents. Pass an existing `token` to add the file to an earlier upload
+    /// so several files can be attached to one comment.
+    pub async fn upload_file_bytes(
+        &self,
+        filename: &str,
+        bytes: Vec<u8>,
+        token: Option<&str>,
+    ) -> Result<Upload> {
+        let mut endpoint = format!("uploads.json?filename={}", urlencoding::encode(filename));
+        if let Some(token_value) = token {
+            endpoint.push_str(&format!("&token={}", urlencoding::encode(token_value)));
+        }
+
+        let content_type = content_type_for_filename(filename);
+        let response: UploadResponse = self.post_bytes(&endpoint, content_type, bytes).await?;
+        Ok(response.upload)
+    }
+
+    /// Upload a file from disk, using its file name for the attachment
+    pub async fn upload_file_from_path(
+        &self,
+        path: impl AsRef<Path>,
+        token: Option<&str>,
+    ) -> Result<Upload> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| ZendeskError::validation("Upload path has no file name"))?;
+        let bytes = tokio::fs::read(path).await?;
+        self.upload_file_bytes(filename, bytes, token).await
+    }
+
+    /// Upload the contents of an async reader
+    pub async fn upload_file_from_reader<R>(
+        &self,
+        filename: &str,
+        mut reader: R,
+        token: Option<&str>,
+    ) -> Result<Upload>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        self.upload_file_bytes(filename, bytes, token).await
+    }
+
+    /// Upload several files under a single token
+    pub async fn upload_files<P>(&self, paths: &[P]) -> Result<Upload>
+    where
+        P: AsRef<Path>,
+    {
+        let (first, rest) = paths
+            .split_first()
+            .ok_or_else(|| ZendeskError::validation("No files to upload"))?;
+
+        let mut upload = self.upload_file_from_path(first, None).await?;
+        for path in rest {
+            upload = self
+                .upload_file_from_path(path, Some(&upload.token))
+                .await?;
+        }
+        Ok(upload)
+    }
+
+    /// Delete an upload that has not yet been attached to a comment
+    pub async fn delete_upload(&self, token: &str) -> Result<()> {
+        let endpoint = format!("uploads/{}.json", urlencoding::encode(token));
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    pub async fn get_attachment(&self, attachment_id: u64) -> Result<CommentAttachment> {
+        let endpoint = format!("attachments/{}.json", attachment_id);
+        let response: AttachmentResponse = self.get(&endpoint).await?;
+        Ok(response.attachment)
+    }
+
+    /// Start downloading an attachment's `content_url`
+    pub async fn download_attachment(
+        &self,
+        attachment: &CommentAttachment,
+    ) -> Result<AttachmentDownload> {
+        let response = self.get_raw(&attachment.content_url).await?;
+        Ok(AttachmentDownload { response })
+    }
+}
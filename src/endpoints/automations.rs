@@ -0,0 +1,97 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::automation::{
+    Automation, AutomationBatchUpdate, AutomationBatchUpdateRequest, AutomationRequest,
+    AutomationResponse, AutomationUpsert, AutomationsResponse,
+};
+use crate::query::QueryParams;
+
+impl ZendeskClient {
+    pub async fn list_automations(&self) -> Result<Vec<Automation>> {
+        let response: AutomationsResponse = self.get("automations.json").await?;
+        Ok(response.automations)
+    }
+
+    /// List automations with query parameters (pagination, sorting, etc.)
+    pub async fn list_automations_with_params(
+        &self,
+        params: &QueryParams,
+    ) -> Result<AutomationsResponse> {
+        self.get_with_params("automations.json", params).await
+    }
+
+    /// List every automation, following `next_page`
+    pub async fn list_all_automations(&self) -> Result<Vec<Automation>> {
+        let mut response: AutomationsResponse = self.get("automations.json").await?;
+        let mut automations = std::mem::take(&mut response.automations);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            automations.append(&mut response.automations);
+        }
+
+        Ok(automations)
+    }
+
+    pub async fn list_active_automations(&self) -> Result<Vec<Automation>> {
+        let response: AutomationsResponse = self.get("automations/active.json").await?;
+        Ok(response.automations)
+    }
+
+    pub async fn get_automation(&self, automation_id: u64) -> Result<Automation> {
+        let endpoint = format!("automations/{}.json", automation_id);
+        let response: AutomationResponse = self.get(&endpoint).await?;
+        Ok(response.automation)
+    }
+
+    pub async fn create_automation(&self, automation: AutomationUpsert) -> Result<Automation> {
+        let request = AutomationRequest { automation };
+        let response: AutomationResponse = self.post("automations.json", &request).await?;
+        Ok(response.automation)
+    }
+
+    pub async fn update_automation(
+        &self,
+        automation_id: u64,
+        automation: AutomationUpsert,
+    ) -> Result<Automation> {
+        let endpoint = format!("automations/{}.json", automation_id);
+        let request = AutomationRequest { automation };
+        let response: AutomationResponse = self.put(&endpoint, &request).await?;
+        Ok(response.automation)
+    }
+
+    pub async fn delete_automation(&self, automation_id: u64) -> Result<()> {
+        let endpoint = format!("automations/{}.json", automation_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Update the position or active flag of many automations at once
+    pub async fn update_many_automations(
+        &self,
+        updates: Vec<AutomationBatchUpdate>,
+    ) -> Result<Vec<Automation>> {
+        let request = AutomationBatchUpdateRequest {
+            automations: updates,
+        };
+        let response: AutomationsResponse =
+            self.put("automations/update_many.json", &request).await?;
+        Ok(response.automations)
+    }
+
+    /// Set the order of automations; `automation_ids` lists them first to last.
+    /// Zendesk has no reorder endpoint for automations, so positions go through `update_many`.
+    pub async fn reorder_automations(&self, automation_ids: Vec<u64>) -> Result<Vec<Automation>> {
+        let updates = automation_ids
+            .into_iter()
+            .zip(1..)
+            .map(|(id, position)| AutomationBatchUpdate {
+                id,
+                position: Some(position),
+                active: None,
+            })
+            .collect();
+        self.update_many_automations(updates).await
+    }
+}
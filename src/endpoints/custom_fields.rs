@@ -0,0 +1,57 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::custom_field::{
+    CustomFieldRegistry, OrganizationField, OrganizationFieldsResponse, UserField,
+    UserFieldsResponse,
+};
+
+impl ZendeskClient {
+    /// List all user field definitions, following pagination
+    pub async fn list_user_fields(&self) -> Result<Vec<UserField>> {
+        let mut response: UserFieldsResponse = self.get("user_fields.json").await?;
+        let mut fields = std::mem::take(&mut response.user_fields);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            fields.append(&mut response.user_fields);
+        }
+
+        Ok(fields)
+    }
+
+    /// List all organization field definitions, following pagination
+    pub async fn list_organization_fields(&self) -> Result<Vec<OrganizationField>> {
+        let mut response: OrganizationFieldsResponse = self.get("organization_fields.json").await?;
+        let mut fields = std::mem::take(&mut response.organization_fields);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            fields.append(&mut response.organization_fields);
+        }
+
+        Ok(fields)
+    }
+
+    /// Load ticket, user and organization field definitions into a registry
+    pub async fn load_custom_field_registry(&self) -> Result<CustomFieldRegistry> {
+        let ticket_fields = self.list_ticket_fields().await?;
+        let user_fields = self.list_user_fields().await?;
+        let organization_fields = self.list_organization_fields().await?;
+
+        Ok(CustomFieldRegistry::new(
+            &ticket_fields,
+            &user_fields,
+            &organization_fields,
+        ))
+    }
+
+    /// Reload field definitions into an existing registry. The registry is left untouched
+    /// if any request fails.
+    pub async fn refresh_custom_field_registry(
+        &self,
+        registry: &mut CustomFieldRegistry,
+    ) -> Result<()> {
+        *registry = self.load_custom_field_registry().await?;
+        Ok(())
+    }
+}
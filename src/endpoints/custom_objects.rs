@@ -38,7 +38,7 @@ impl ZendeskClient {
 
     pub async fn delete_custom_object(&self, custom_object_key: &str) -> Result<()> {
         let endpoint = format!("custom_objects/{}.json", custom_object_key);
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 
@@ -114,7 +114,7 @@ impl ZendeskClient {
             "custom_objects/{}/fields/{}.json",
             custom_object_key, field_key_or_id
         );
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 
@@ -235,7 +235,7 @@ impl ZendeskClient {
             "custom_objects/{}/records/{}.json",
             custom_object_key, record_id
         );
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 
@@ -0,0 +1,212 @@
+use crate::client::ZendeskClient;
+use crate::endpoints::job_statuses::submit_in_chunks;
+use crate::endpoints::tickets::{BULK_CHUNK_SIZE, join_ids};
+use crate::errors::Result;
+use crate::models::group::{
+    Group, GroupMembership, GroupMembershipCreate, GroupMembershipRequest, GroupMembershipResponse,
+    GroupMembershipsCreateManyRequest, GroupMembershipsResponse, GroupRequest, GroupResponse,
+    GroupUpsert, GroupsResponse,
+};
+use crate::models::job_status::{BulkJobResponse, JobStatus};
+
+impl ZendeskClient {
+    pub async fn list_groups(&self) -> Result<Vec<Group>> {
+        let response: GroupsResponse = self.get("groups.json").await?;
+        Ok(response.groups)
+    }
+
+    /// List every group, following `next_page`
+    pub async fn list_all_groups(&self) -> Result<Vec<Group>> {
+        let mut response: GroupsResponse = self.get("groups.json").await?;
+        let mut groups = std::mem::take(&mut response.groups);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            groups.append(&mut response.groups);
+        }
+
+        Ok(groups)
+    }
+
+    /// Groups the current user may assign tickets to
+    pub async fn list_assignable_groups(&self) -> Result<Vec<Group>> {
+        let response: GroupsResponse = self.get("groups/assignable.json").await?;
+        Ok(response.groups)
+    }
+
+    /// Groups a user belongs to
+    pub async fn list_user_groups(&self, user_id: u64) -> Result<Vec<Group>> {
+        let endpoint = format!("users/{}/groups.json", user_id);
+        let response: GroupsResponse = self.get(&endpoint).await?;
+        Ok(response.groups)
+    }
+
+    pub async fn get_group(&self, group_id: u64) -> Result<Group> {
+        let endpoint = format!("groups/{}.json", group_id);
+        let response: GroupResponse = self.get(&endpoint).await?;
+        Ok(response.group)
+    }
+
+    pub async fn create_group(&self, group: GroupUpsert) -> Result<Group> {
+        let request = GroupRequest { group };
+        let response: GroupResponse = self.post("groups.json", &request).await?;
+        Ok(response.group)
+    }
+
+    pub async fn update_group(&self, group_id: u64, group: GroupUpsert) -> Result<Group> {
+        let endpoint = format!("groups/{}.json", group_id);
+        let request = GroupRequest { group };
+        let response: GroupResponse = self.put(&endpoint, &request).await?;
+        Ok(response.group)
+    }
+
+    pub async fn delete_group(&self, group_id: u64) -> Result<()> {
+        let endpoint = format!("groups/{}.json", group_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    // Group memberships
+
+    pub async fn list_group_memberships(&self) -> Result<Vec<GroupMembership>> {
+        let response: GroupMembershipsResponse = self.get("group_memberships.json").await?;
+        Ok(response.group_memberships)
+    }
+
+    /// Memberships of one group, following `next_page`
+    pub async fn list_memberships_of_group(&self, group_id: u64) -> Result<Vec<GroupMembership>> {
+        let endpoint = format!("groups/{}/memberships.json", group_id);
+        self.list_all_group_memberships_at(&endpoint).await
+    }
+
+    /// Memberships of one user, following `next_page`
+    pub async fn list_user_group_memberships(&self, user_id: u64) -> Result<Vec<GroupMembership>> {
+        let endpoint = format!("users/{}/group_memberships.json", user_id);
+        self.list_all_group_memberships_at(&endpoint).await
+    }
+
+    async fn list_all_group_memberships_at(&self, endpoint: &str) -> Result<Vec<GroupMembership>> {
+        let mut response: GroupMembershipsResponse = self.get(endpoint).await?;
+        let mut memberships = std::mem::take(&mut response.group_memberships);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            memberships.append(&mut response.group_memberships);
+        }
+
+        Ok(memberships)
+    }
+
+    pub async fn get_group_membership(&self, membership_id: u64) -> Result<GroupMembership> {
+        let endpoint = format!("group_memberships/{}.json", membership_id);
+        let response: GroupMembershipResponse = self.get(&endpoint).await?;
+        Ok(response.group_membership)
+    }
+
+    /// Add an agent to a group
+    pub async fn create_group_membership(
+        &self,
+        user_id: u64,
+        group_id: u64,
+    ) -> Result<GroupMembership> {
+        let request = GroupMembershipRequest {
+            group_membership: GroupMembershipCreate::new(user_id, group_id),
+        };
+        let response: GroupMembershipResponse =
+            self.post("group_memberships.json", &request).await?;
+        Ok(response.group_membership)
+    }
+
+    /// Remove an agent from a group
+    pub async fn delete_group_membership(&self, membership_id: u64) -> Result<()> {
+        let endpoint = format!("group_memberships/{}.json", membership_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Make a membership the user's default group. Returns the user's memberships.
+    pub async fn set_default_group_membership(
+        &self,
+        user_id: u64,
+        membership_id: u64,
+    ) -> Result<Vec<GroupMembership>> {
+        let endpoint = format!(
+            "users/{}/group_memberships/{}/make_default.json",
+            user_id, membership_id
+        );
+        let response: GroupMembershipsResponse =
+            self.put(&endpoint, &serde_json::json!({})).await?;
+        Ok(response.group_memberships)
+    }
+
+    /// Create many memberships via `group_memberships/create_many`, split into batches
+    /// of 100. Returns one job status per batch.
+    pub async fn create_many_group_memberships(
+        &self,
+        memberships: Vec<GroupMembershipCreate>,
+    ) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(&memberships, BULK_CHUNK_SIZE, |chunk| async move {
+            let request = GroupMembershipsCreateManyRequest {
+                group_memberships: chunk.to_vec(),
+            };
+            let response: BulkJobResponse = self
+                .post("group_memberships/create_many.json", &request)
+                .await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Delete many memberships via `group_memberships/destroy_many`
+    pub async fn destroy_many_group_memberships(
+        &self,
+        membership_ids: &[u64],
+    ) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(membership_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!(
+                "group_memberships/destroy_many.json?ids={}",
+                join_ids(chunk)
+            );
+            let response: BulkJobResponse = self.delete(&endpoint).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Move an agent from one group to another. The new membership is created first and
+    /// becomes the default if the old one was, so the agent is never left without a group.
+    /// Returns the membership in `to_group_id`.
+    pub async fn move_user_between_groups(
+        &self,
+        user_id: u64,
+        from_group_id: u64,
+        to_group_id: u64,
+    ) -> Result<GroupMembership> {
+        let memberships = self.list_user_group_memberships(user_id).await?;
+        let from = memberships
+            .iter()
+            .find(|membership| membership.group_id == from_group_id);
+
+        let mut to = match memberships
+            .iter()
+            .find(|membership| membership.group_id == to_group_id)
+        {
+            Some(existing) => existing.clone(),
+            None => self.create_group_membership(user_id, to_group_id).await?,
+        };
+
+        if let Some(from) = from.filter(|_| from_group_id != to_group_id) {
+            if from.default
+                && let Some(to_id) = to.id
+            {
+                self.set_default_group_membership(user_id, to_id).await?;
+                to.default = true;
+            }
+            if let Some(from_id) = from.id {
+                self.delete_group_membership(from_id).await?;
+            }
+        }
+
+        Ok(to)
+    }
+}
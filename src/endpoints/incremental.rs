@@ -0,0 +1,211 @@
+use crate::client::ZendeskClient;
+use crate::errors::{Result, ZendeskError};
+use crate::models::incremental::{ExportPosition, IncrementalPage, TicketEvent};
+use crate::models::organization::Organization;
+use crate::models::ticket::Ticket;
+use crate::models::ticket_metric::TicketMetricEvent;
+use crate::models::user::User;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+/// Zendesk allows 10 incremental export requests per minute
+const DEFAULT_MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(6);
+
+/// A resumable incremental export, fetched one page at a time
+pub struct IncrementalExport<'a, T> {
+    client: &'a ZendeskClient,
+    endpoint: &'static str,
+    resource_key: &'static str,
+    cursor_based: bool,
+    position: ExportPosition,
+    include: Vec<String>,
+    page_size: Option<u32>,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+    finished: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> IncrementalExport<'a, T>
+where
+    T: DeserializeOwned,
+{
+    fn new(
+        client: &'a ZendeskClient,
+        endpoint: &'static str,
+        resource_key: &'static str,
+        cursor_based: bool,
+        position: ExportPosition,
+    ) -> Self {
+        Self {
+            client,
+            endpoint,
+            resource_key,
+            cursor_based,
+            position,
+            include: Vec::new(),
+            page_size: None,
+            min_interval: DEFAULT_MIN_REQUEST_INTERVAL,
+            last_request: None,
+            finished: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Side-load related resources with every page (e.g. "users", "comment_events")
+    pub fn with_include(mut self, resources: &[&str]) -> Self {
+        self.include = resources.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Number of records per page (cursor-based exports only)
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Minimum delay between requests; defaults to six seconds to stay within the
+    /// incremental export rate limit
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// The position the next page will be requested from. Persist this to resume later.
+    pub fn position(&self) -> &ExportPosition {
+        &self.position
+    }
+
+    /// Whether the last fetched page reported `end_of_stream`
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Fetch the next page, or `None` once the end of the stream has been reached
+    pub async fn try_next_page(&mut self) -> Result<Option<IncrementalPage<T>>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let endpoint = self.page_endpoint()?;
+        self.wait_for_rate_limit().await;
+        self.last_request = Some(Instant::now());
+        let response: serde_json::Value = self.client.get(&endpoint).await?;
+
+        let page = IncrementalPage::from_response(response, self.resource_key)?;
+        if let Some(next_position) = &page.next_position {
+            self.position = next_position.clone();
+        }
+        self.finished = page.end_of_stream;
+
+        Ok(Some(page))
+    }
+
+    fn page_endpoint(&self) -> Result<String> {
+        let mut params = Vec::new();
+
+        match &self.position {
+            ExportPosition::StartTime(start_time) => {
+                params.push(format!("start_time={}", start_time));
+            }
+            ExportPosition::Cursor(cursor) if self.cursor_based => {
+                params.push(format!("cursor={}", urlencoding::encode(cursor)));
+            }
+            ExportPosition::Cursor(_) => {
+                return Err(ZendeskError::validation(format!(
+                    "{} is a time-based export and cannot resume from a cursor",
+                    self.endpoint
+                )));
+            }
+        }
+
+        if !self.include.is_empty() {
+            params.push(format!("include={}", self.include.join(",")));
+        }
+        if let Some(size) = self.page_size {
+            params.push(format!("per_page={}", size));
+        }
+
+        Ok(format!("{}?{}", self.endpoint, params.join("&")))
+    }
+
+    async fn wait_for_rate_limit(&self) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < self.min_interval {
+                tokio::time::sleep(self.min_interval - elapsed).await;
+            }
+        }
+    }
+}
+
+impl ZendeskClient {
+    /// Cursor-based incremental export of tickets
+    pub fn incremental_ticket_export(
+        &self,
+        position: ExportPosition,
+    ) -> IncrementalExport<'_, Ticket> {
+        IncrementalExport::new(
+            self,
+            "incremental/tickets/cursor.json",
+            "tickets",
+            true,
+            position,
+        )
+    }
+
+    /// Cursor-based incremental export of users
+    pub fn incremental_user_export(&self, position: ExportPosition) -> IncrementalExport<'_, User> {
+        IncrementalExport::new(
+            self,
+            "incremental/users/cursor.json",
+            "users",
+            true,
+            position,
+        )
+    }
+
+    /// Time-based incremental export of organizations
+    pub fn incremental_organization_export(
+        &self,
+        start_time: i64,
+    ) -> IncrementalExport<'_, Organization> {
+        IncrementalExport::new(
+            self,
+            "incremental/organizations.json",
+            "organizations",
+            false,
+            ExportPosition::StartTime(start_time),
+        )
+    }
+
+    /// Time-based incremental export of ticket events
+    pub fn incremental_ticket_event_export(
+        &self,
+        start_time: i64,
+    ) -> IncrementalExport<'_, TicketEvent> {
+        IncrementalExport::new(
+            self,
+            "incremental/ticket_events.json",
+            "ticket_events",
+            false,
+            ExportPosition::StartTime(start_time),
+        )
+    }
+
+    /// Time-based incremental export of ticket metric events (SLA activations, breaches,
+    /// fulfilments and status updates)
+    pub fn incremental_ticket_metric_event_export(
+        &self,
+        start_time: i64,
+    ) -> IncrementalExport<'_, TicketMetricEvent> {
+        IncrementalExport::new(
+            self,
+            "incremental/ticket_metric_events.json",
+            "ticket_metric_events",
+            false,
+            ExportPosition::StartTime(start_time),
+        )
+    }
+}
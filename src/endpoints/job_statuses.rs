@@ -0,0 +1,265 @@
+use crate::client::ZendeskClient;
+use crate::errors::{Result, ZendeskError};
+use crate::models::job_status::{BulkJobResponse, JobState, JobStatus, JobStatusesResponse};
+use std::future::Future;
+use std::time::Duration;
+
+const DEFAULT_INITIAL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(30);
+const DEFAULT_MAX_WAIT: Duration = Duration::from_secs(60 * 60);
+
+/// A background job that can be polled until it finishes
+#[derive(Debug, Clone)]
+pub struct JobHandle<'a> {
+    client: &'a ZendeskClient,
+    status: JobStatus,
+    initial_interval: Duration,
+    max_interval: Duration,
+    max_wait: Duration,
+}
+
+impl<'a> JobHandle<'a> {
+    /// Job id as reported by Zendesk
+    pub fn id(&self) -> &str {
+        self.status.id.as_deref().unwrap_or_default()
+    }
+
+    /// The most recently fetched status
+    pub fn status(&self) -> &JobStatus {
+        &self.status
+    }
+
+    /// Set the first polling delay and the cap the delay doubles towards
+    pub fn with_poll_interval(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_interval = initial;
+        self.max_interval = max.max(initial);
+        self
+    }
+
+    /// Give up with [`ZendeskError::Timeout`] if the job has not finished after `max_wait`
+    /// (one hour by default)
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Fetch the latest status from `job_statuses/{id}`
+    pub async fn refresh(&mut self) -> Result<&JobStatus> {
+        self.status = self.client.get_job_status_by_id(self.id()).await?;
+        Ok(&self.status)
+    }
+
+    /// Poll with exponential backoff until the job is completed, failed or killed.
+    /// Fails if the job reports a missing or unrecognised status, or does not finish
+    /// within the maximum wait.
+    pub async fn wait(self) -> Result<JobStatus> {
+        self.wait_with_progress(|_| {}).await
+    }
+
+    /// Like [`JobHandle::wait`], calling `on_progress` with every fetched status
+    pub async fn wait_with_progress<F>(mut self, mut on_progress: F) -> Result<JobStatus>
+    where
+        F: FnMut(&JobStatus),
+    {
+        let deadline = tokio::time::Instant::now() + self.max_wait;
+        let mut interval = self.initial_interval;
+        on_progress(&self.status);
+
+        loop {
+            match self.status.state() {
+                Some(state) if state.is_finished() => return Ok(self.status),
+                Some(JobState::Unknown(status)) => {
+                    return Err(ZendeskError::UnexpectedResponse(format!(
+                        "job {} reported unknown status \"{}\"",
+                        self.id(),
+                        status
+                    )));
+                }
+                None => {
+                    return Err(ZendeskError::UnexpectedResponse(format!(
+                        "job {} reported no status",
+                        self.id()
+                    )));
+                }
+                Some(_) => {}
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(ZendeskError::Timeout(format!(
+                    "job {} still {} after {:?}",
+                    self.id(),
+                    self.status.status.as_deref().unwrap_or_default(),
+                    self.max_wait
+                )));
+            }
+            tokio::time::sleep(interval.min(deadline - now)).await;
+            interval = (interval * 2).min(self.max_interval);
+            self.refresh().await?;
+            on_progress(&self.status);
+        }
+    }
+}
+
+impl ZendeskClient {
+    /// Get an account-wide job status (as returned by bulk ticket and user operations)
+    pub async fn get_job_status_by_id(&self, job_id: &str) -> Result<JobStatus> {
+        let endpoint = format!("job_statuses/{}.json", job_id);
+        let response: BulkJobResponse = self.get(&endpoint).await?;
+        Ok(response.job_status)
+    }
+
+    /// List recent background jobs for the account
+    pub async fn list_job_statuses(&self) -> Result<Vec<JobStatus>> {
+        let response: JobStatusesResponse = self.get("job_statuses.json").await?;
+        Ok(response.job_statuses)
+    }
+
+    /// Get several job statuses in one request
+    pub async fn get_many_job_statuses(&self, job_ids: &[&str]) -> Result<Vec<JobStatus>> {
+        let endpoint = format!("job_statuses/show_many.json?ids={}", job_ids.join(","));
+        let response: JobStatusesResponse = self.get(&endpoint).await?;
+        Ok(response.job_statuses)
+    }
+
+    /// Wrap a job status returned by a bulk endpoint so it can be awaited
+    pub fn job_handle(&self, status: JobStatus) -> Result<JobHandle<'_>> {
+        if status.id.is_none() {
+            return Err(ZendeskError::validation("Job status has no id to poll"));
+        }
+
+        Ok(JobHandle {
+            client: self,
+            status,
+            initial_interval: DEFAULT_INITIAL_POLL_INTERVAL,
+            max_interval: DEFAULT_MAX_POLL_INTERVAL,
+            max_wait: DEFAULT_MAX_WAIT,
+        })
+    }
+
+    /// Poll a job status at a fixed interval until it is completed, failed or killed
+    pub async fn wait_for_job_status(
+        &self,
+        job_id: &str,
+        poll_interval: Duration,
+    ) -> Result<JobStatus> {
+        let status = self.get_job_status_by_id(job_id).await?;
+        self.job_handle(status)?
+            .with_poll_interval(poll_interval, poll_interval)
+            .wait()
+            .await
+    }
+
+    /// Wait for every job in a chunked bulk operation, in order
+    pub async fn wait_for_jobs(&self, statuses: Vec<JobStatus>) -> Result<Vec<JobStatus>> {
+        let mut finished = Vec::with_capacity(statuses.len());
+        for status in statuses {
+            finished.push(self.job_handle(status)?.wait().await?);
+        }
+        Ok(finished)
+    }
+}
+
+/// Submit `items` in chunks of at most `chunk_size`, one request per chunk, collecting the
+/// job each request starts. If a chunk fails, the error is wrapped in
+/// [`ZendeskError::Bulk`] together with the jobs of the chunks already accepted, since
+/// those keep running on Zendesk's side.
+pub(crate) async fn submit_in_chunks<'a, T, F, Fut>(
+    items: &'a [T],
+    chunk_size: usize,
+    mut submit: F,
+) -> Result<Vec<JobStatus>>
+where
+    F: FnMut(&'a [T]) -> Fut,
+    Fut: Future<Output = Result<JobStatus>>,
+{
+    let mut submitted = Vec::new();
+    for chunk in items.chunks(chunk_size) {
+        match submit(chunk).await {
+            Ok(job) => submitted.push(job),
+            Err(source) => {
+                return Err(ZendeskError::Bulk {
+                    submitted,
+                    source: Box::new(source),
+                });
+            }
+        }
+    }
+    Ok(submitted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthMethod;
+    use crate::config::ZendeskConfig;
+
+    fn client() -> ZendeskClient {
+        let config = ZendeskConfig::new("example", AuthMethod::api_token("a@example.com", "t"));
+        ZendeskClient::new(config).unwrap()
+    }
+
+    fn job(id: &str) -> JobStatus {
+        serde_json::from_value(serde_json::json!({ "id": id, "status": "queued" })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_wait_stops_on_unknown_status_and_deadline() {
+        let client = client();
+        let unknown: JobStatus =
+            serde_json::from_value(serde_json::json!({ "id": "a", "status": "paused" })).unwrap();
+        let error = client
+            .job_handle(unknown)
+            .unwrap()
+            .wait()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ZendeskError::UnexpectedResponse(_)));
+
+        let error = client
+            .job_handle(job("b"))
+            .unwrap()
+            .with_max_wait(Duration::ZERO)
+            .wait()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ZendeskError::Timeout(_)));
+    }
+
+    #[tokio::test]
+    async fn test_submit_in_chunks_splits_and_keeps_submitted_jobs() {
+        let items: Vec<u32> = (0..250).collect();
+        let mut sizes = Vec::new();
+        let jobs = submit_in_chunks(&items, 100, |chunk| {
+            sizes.push(chunk.len());
+            let id = chunk[0].to_string();
+            async move { Ok(job(&id)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(sizes, vec![100, 100, 50]);
+        assert_eq!(jobs.len(), 3);
+
+        let error = submit_in_chunks(&items, 100, |chunk| {
+            let first = chunk[0];
+            async move {
+                if first == 200 {
+                    Err(ZendeskError::api(500, "boom"))
+                } else {
+                    Ok(job(&first.to_string()))
+                }
+            }
+        })
+        .await
+        .unwrap_err();
+        let ids: Vec<_> = error
+            .submitted_jobs()
+            .iter()
+            .map(|job| job.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["0", "100"]);
+        assert!(
+            matches!(error, ZendeskError::Bulk { ref source, .. } if matches!(**source, ZendeskError::Api { status: 500, .. }))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::ticket::Ticket;
+use crate::models::ticket_macro::{
+    Macro, MacroApplyResponse, MacroApplyResult, MacroRequest, MacroResponse, MacroUpsert,
+    MacrosResponse,
+};
+
+impl ZendeskClient {
+    /// List every macro, following `next_page`
+    pub async fn list_macros(&self) -> Result<Vec<Macro>> {
+        let mut response: MacrosResponse = self.get("macros.json").await?;
+        let mut macros = std::mem::take(&mut response.macros);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            macros.append(&mut response.macros);
+        }
+
+        Ok(macros)
+    }
+
+    /// List the active macros available to the current user
+    pub async fn list_active_macros(&self) -> Result<Vec<Macro>> {
+        let response: MacrosResponse = self.get("macros/active.json").await?;
+        Ok(response.macros)
+    }
+
+    /// Search macros by title
+    pub async fn search_macros(&self, query: &str) -> Result<Vec<Macro>> {
+        let endpoint = format!("macros/search.json?query={}", urlencoding::encode(query));
+        let response: MacrosResponse = self.get(&endpoint).await?;
+        Ok(response.macros)
+    }
+
+    pub async fn get_macro(&self, macro_id: u64) -> Result<Macro> {
+        let endpoint = format!("macros/{}.json", macro_id);
+        let response: MacroResponse = self.get(&endpoint).await?;
+        Ok(response.ticket_macro)
+    }
+
+    pub async fn create_macro(&self, ticket_macro: MacroUpsert) -> Result<Macro> {
+        let request = MacroRequest { ticket_macro };
+        let response: MacroResponse = self.post("macros.json", &request).await?;
+        Ok(response.ticket_macro)
+    }
+
+    pub async fn update_macro(&self, macro_id: u64, ticket_macro: MacroUpsert) -> Result<Macro> {
+        let endpoint = format!("macros/{}.json", macro_id);
+        let request = MacroRequest { ticket_macro };
+        let response: MacroResponse = self.put(&endpoint, &request).await?;
+        Ok(response.ticket_macro)
+    }
+
+    pub async fn delete_macro(&self, macro_id: u64) -> Result<()> {
+        let endpoint = format!("macros/{}.json", macro_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Preview the changes a macro makes, independent of any ticket
+    pub async fn apply_macro(&self, macro_id: u64) -> Result<MacroApplyResult> {
+        let endpoint = format!("macros/{}/apply.json", macro_id);
+        let response: MacroApplyResponse = self.get(&endpoint).await?;
+        Ok(response.result)
+    }
+
+    /// Preview the ticket and comment a macro would produce on a ticket, without saving
+    pub async fn apply_macro_to_ticket(
+        &self,
+        ticket_id: u64,
+        macro_id: u64,
+    ) -> Result<MacroApplyResult> {
+        let endpoint = format!("tickets/{}/macros/{}/apply.json", ticket_id, macro_id);
+        let response: MacroApplyResponse = self.get(&endpoint).await?;
+        Ok(response.result)
+    }
+
+    /// Apply a macro to a ticket and save the result, including its comment, ticket type
+    /// and custom field changes. Fails without saving anything if part of the result
+    /// cannot be sent.
+    pub async fn run_macro_on_ticket(&self, ticket_id: u64, macro_id: u64) -> Result<Ticket> {
+        let result = self.apply_macro_to_ticket(ticket_id, macro_id).await?;
+        let changes = result.to_ticket_changes()?;
+        self.update_ticket_changes(ticket_id, changes).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::mock_client;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_macros_follows_next_page() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/macros.json"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "macros": [{ "id": 2, "title": "Second", "actions": [] }],
+                "next_page": null
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/macros.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "macros": [{ "id": 1, "title": "First", "actions": [] }],
+                "next_page": format!("{}/api/v2/macros.json?page=2", server.uri())
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let macros = client.list_macros().await.unwrap();
+        let ids: Vec<_> = macros.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![Some(1), Some(2)]);
+    }
+
+    #[tokio::test]
+    async fn test_run_macro_sends_every_change() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/tickets/7/macros/3/apply.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": {
+                    "ticket": {
+                        "id": 7,
+                        "url": "https://example.zendesk.com/api/v2/tickets/7.json",
+                        "status": "pending",
+                        "type": "incident",
+                        "custom_fields": [{ "id": 11, "value": "refund" }],
+                        "brand_id": 5
+                    },
+                    "comment": {
+                        "body": "Refund sent",
+                        "html_body": "<p>Refund sent</p>",
+                        "public": false
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v2/tickets/7.json"))
+            .and(body_json(json!({
+                "ticket": {
+                    "comment": {
+                        "body": "Refund sent",
+                        "html_body": "<p>Refund sent</p>",
+                        "public": false
+                    },
+                    "status": "pending",
+                    "type": "incident",
+                    "custom_fields": [{ "id": 11, "value": "refund" }],
+                    "brand_id": 5
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ticket": { "id": 7, "subject": "Refund", "description": "Refund please" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let ticket = client.run_macro_on_ticket(7, 3).await.unwrap();
+        assert_eq!(ticket.id, Some(7));
+    }
+}
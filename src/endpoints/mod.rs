@@ -1,6 +1,25 @@
+pub mod attachments;
+pub mod automations;
+pub mod custom_fields;
 pub mod custom_objects;
+pub mod groups;
+pub mod incremental;
+pub mod job_statuses;
+pub mod macros;
 pub mod organizations;
+pub mod redactions;
 pub mod relationships;
+pub mod satisfaction_ratings;
+pub mod schedules;
 pub mod search;
+pub mod sla_policies;
+pub mod ticket_audits;
+pub mod ticket_fields;
+pub mod ticket_imports;
+pub mod ticket_metrics;
 pub mod tickets;
+pub mod triggers;
+pub mod user_identities;
+pub mod user_merges;
 pub mod users;
+pub mod views;
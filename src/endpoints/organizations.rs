@@ -31,7 +31,7 @@ impl ZendeskClient {
 
     pub async fn delete_organization(&self, organization_id: u64) -> Result<()> {
         let endpoint = format!("organizations/{}.json", organization_id);
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 
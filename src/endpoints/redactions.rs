@@ -0,0 +1,107 @@
+use crate::client::ZendeskClient;
+use crate::errors::{Result, ZendeskError};
+use crate::models::attachment::AttachmentResponse;
+use crate::models::redaction::{
+    CommentRedactionRequest, CommentRedactionUpdate, PendingRedaction, find_redactions,
+};
+use crate::models::ticket::{CommentAttachment, TicketComment};
+use regex::Regex;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct CommentResponse {
+    comment: TicketComment,
+}
+
+impl ZendeskClient {
+    /// Permanently remove a string from a ticket comment
+    pub async fn redact_comment_text(
+        &self,
+        ticket_id: u64,
+        comment_id: u64,
+        text: impl Into<String>,
+    ) -> Result<TicketComment> {
+        let endpoint = format!("tickets/{}/comments/{}/redact.json", ticket_id, comment_id);
+        let request = CommentRedactionRequest { text: text.into() };
+        let response: CommentResponse = self.put(&endpoint, &request).await?;
+        Ok(response.comment)
+    }
+
+    /// Redact a comment through the agent workspace `comment_redactions` API
+    pub async fn redact_comment(
+        &self,
+        comment_id: u64,
+        redaction: CommentRedactionUpdate,
+    ) -> Result<TicketComment> {
+        let endpoint = format!("comment_redactions/{}.json", comment_id);
+        let response: CommentResponse = self.put(&endpoint, &redaction).await?;
+        Ok(response.comment)
+    }
+
+    /// Permanently replace an attachment on a comment with an empty `redacted.txt` file
+    pub async fn redact_comment_attachment(
+        &self,
+        ticket_id: u64,
+        comment_id: u64,
+        attachment_id: u64,
+    ) -> Result<CommentAttachment> {
+        let endpoint = format!(
+            "tickets/{}/comments/{}/attachments/{}/redact.json",
+            ticket_id, comment_id, attachment_id
+        );
+        let response: AttachmentResponse = self.put(&endpoint, &serde_json::json!({})).await?;
+        Ok(response.attachment)
+    }
+
+    /// Fetch every comment on a ticket, following pagination
+    pub async fn get_all_ticket_comments(&self, ticket_id: u64) -> Result<Vec<TicketComment>> {
+        let mut response = self
+            .get_ticket_comments_with_pagination(ticket_id, None)
+            .await?;
+        let mut comments = std::mem::take(&mut response.comments);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self
+                .get_ticket_comments_with_pagination(ticket_id, Some(&next_page))
+                .await?;
+            comments.append(&mut response.comments);
+        }
+
+        Ok(comments)
+    }
+
+    /// Find comment text on a ticket matching any of `patterns` without changing anything
+    pub async fn find_comment_redactions(
+        &self,
+        ticket_id: u64,
+        patterns: &[Regex],
+    ) -> Result<Vec<PendingRedaction>> {
+        let comments = self.get_all_ticket_comments(ticket_id).await?;
+        Ok(find_redactions(ticket_id, &comments, patterns))
+    }
+
+    /// Redact every match of `patterns` in a ticket's comments, returning what was redacted.
+    /// If a redaction fails, the error is a [`ZendeskError::Redaction`] carrying the ones
+    /// already applied.
+    pub async fn redact_comments_matching(
+        &self,
+        ticket_id: u64,
+        patterns: &[Regex],
+    ) -> Result<Vec<PendingRedaction>> {
+        let redactions = self.find_comment_redactions(ticket_id, patterns).await?;
+        let mut applied = Vec::with_capacity(redactions.len());
+        for redaction in redactions {
+            if let Err(source) = self
+                .redact_comment_text(redaction.ticket_id, redaction.comment_id, &redaction.text)
+                .await
+            {
+                return Err(ZendeskError::Redaction {
+                    applied,
+                    source: Box::new(source),
+                });
+            }
+            applied.push(redaction);
+        }
+        Ok(applied)
+    }
+}
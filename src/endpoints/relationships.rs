@@ -361,21 +361,21 @@ impl ZendeskClient {
     /// Delete a lookup relationship field
     pub async fn delete_ticket_lookup_field(&self, field_id: u64) -> Result<()> {
         let endpoint = format!("api/v2/ticket_fields/{}.json", field_id);
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 
     /// Delete a user lookup relationship field
     pub async fn delete_user_lookup_field(&self, field_id: u64) -> Result<()> {
         let endpoint = format!("api/v2/user_fields/{}.json", field_id);
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 
     /// Delete an organization lookup relationship field
     pub async fn delete_organization_lookup_field(&self, field_id: u64) -> Result<()> {
         let endpoint = format!("api/v2/organization_fields/{}.json", field_id);
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 }
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::satisfaction_rating::{
+    CsatReport, SatisfactionRating, SatisfactionRatingCreate, SatisfactionRatingCreateRequest,
+    SatisfactionRatingFilter, SatisfactionRatingResponse, SatisfactionRatingsResponse,
+    SatisfactionReason, SatisfactionReasonResponse, SatisfactionReasonsResponse,
+};
+
+impl ZendeskClient {
+    /// List one page of satisfaction ratings using cursor pagination
+    pub async fn list_satisfaction_ratings(
+        &self,
+        filter: &SatisfactionRatingFilter,
+        cursor: Option<&str>,
+        page_size: Option<u32>,
+    ) -> Result<SatisfactionRatingsResponse> {
+        let mut params = filter.query_params();
+        if let Some(cursor_val) = cursor {
+            params.push(format!("page[after]={}", urlencoding::encode(cursor_val)));
+        }
+        params.push(format!("page[size]={}", page_size.unwrap_or(100)));
+
+        let endpoint = format!("satisfaction_ratings.json?{}", params.join("&"));
+        self.get(&endpoint).await
+    }
+
+    /// List every satisfaction rating matching `filter`, following the cursor
+    pub async fn list_all_satisfaction_ratings(
+        &self,
+        filter: &SatisfactionRatingFilter,
+    ) -> Result<Vec<SatisfactionRating>> {
+        let mut response = self.list_satisfaction_ratings(filter, None, None).await?;
+        let mut ratings = std::mem::take(&mut response.satisfaction_ratings);
+
+        while let Some(cursor) = response.next_cursor().map(str::to_string) {
+            response = self
+                .list_satisfaction_ratings(filter, Some(&cursor), None)
+                .await?;
+            ratings.append(&mut response.satisfaction_ratings);
+        }
+
+        Ok(ratings)
+    }
+
+    pub async fn get_satisfaction_rating(&self, rating_id: u64) -> Result<SatisfactionRating> {
+        let endpoint = format!("satisfaction_ratings/{}.json", rating_id);
+        let response: SatisfactionRatingResponse = self.get(&endpoint).await?;
+        Ok(response.satisfaction_rating)
+    }
+
+    /// Rate a solved ticket; must be made as the ticket's requester
+    pub async fn create_satisfaction_rating(
+        &self,
+        ticket_id: u64,
+        rating: SatisfactionRatingCreate,
+    ) -> Result<SatisfactionRating> {
+        let endpoint = format!("tickets/{}/satisfaction_rating.json", ticket_id);
+        let request = SatisfactionRatingCreateRequest {
+            satisfaction_rating: rating,
+        };
+        let response: SatisfactionRatingResponse = self.post(&endpoint, &request).await?;
+        Ok(response.satisfaction_rating)
+    }
+
+    pub async fn list_satisfaction_reasons(&self) -> Result<Vec<SatisfactionReason>> {
+        let response: SatisfactionReasonsResponse = self.get("satisfaction_reasons.json").await?;
+        Ok(response.reasons)
+    }
+
+    pub async fn get_satisfaction_reason(&self, reason_id: u64) -> Result<SatisfactionReason> {
+        let endpoint = format!("satisfaction_reasons/{}.json", reason_id);
+        let response: SatisfactionReasonResponse = self.get(&endpoint).await?;
+        Ok(response.reason)
+    }
+
+    /// Aggregate CSAT overall and per assignee and group for ratings created in a range
+    pub async fn csat_report(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<CsatReport> {
+        let filter = SatisfactionRatingFilter::new().between(start_time, end_time);
+        let ratings = self.list_all_satisfaction_ratings(&filter).await?;
+        Ok(CsatReport::from_ratings(&ratings))
+    }
+}
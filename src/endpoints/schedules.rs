@@ -0,0 +1,114 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::schedule::{
+    BusinessCalendar, Holiday, HolidayRequest, HolidayResponse, HolidaysResponse, Schedule,
+    ScheduleInterval, ScheduleRequest, ScheduleResponse, ScheduleUpsert, SchedulesResponse,
+    Workweek, WorkweekRequest, WorkweekResponse,
+};
+
+impl ZendeskClient {
+    pub async fn list_schedules(&self) -> Result<Vec<Schedule>> {
+        let response: SchedulesResponse = self.get("business_hours/schedules.json").await?;
+        Ok(response.schedules)
+    }
+
+    pub async fn get_schedule(&self, schedule_id: u64) -> Result<Schedule> {
+        let endpoint = format!("business_hours/schedules/{}.json", schedule_id);
+        let response: ScheduleResponse = self.get(&endpoint).await?;
+        Ok(response.schedule)
+    }
+
+    /// Create a schedule. Zendesk starts it with Monday to Friday, 09:00 to 17:00; use
+    /// `update_schedule_workweek` to change the open hours.
+    pub async fn create_schedule(&self, schedule: ScheduleUpsert) -> Result<Schedule> {
+        let request = ScheduleRequest { schedule };
+        let response: ScheduleResponse =
+            self.post("business_hours/schedules.json", &request).await?;
+        Ok(response.schedule)
+    }
+
+    pub async fn update_schedule(
+        &self,
+        schedule_id: u64,
+        schedule: ScheduleUpsert,
+    ) -> Result<Schedule> {
+        let endpoint = format!("business_hours/schedules/{}.json", schedule_id);
+        let request = ScheduleRequest { schedule };
+        let response: ScheduleResponse = self.put(&endpoint, &request).await?;
+        Ok(response.schedule)
+    }
+
+    /// Replace the open hours of a schedule
+    pub async fn update_schedule_workweek(
+        &self,
+        schedule_id: u64,
+        intervals: Vec<ScheduleInterval>,
+    ) -> Result<Vec<ScheduleInterval>> {
+        let endpoint = format!("business_hours/schedules/{}/workweek.json", schedule_id);
+        let request = WorkweekRequest {
+            workweek: Workweek { intervals },
+        };
+        let response: WorkweekResponse = self.put(&endpoint, &request).await?;
+        Ok(response.workweek.intervals)
+    }
+
+    pub async fn delete_schedule(&self, schedule_id: u64) -> Result<()> {
+        let endpoint = format!("business_hours/schedules/{}.json", schedule_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    pub async fn list_holidays(&self, schedule_id: u64) -> Result<Vec<Holiday>> {
+        let endpoint = format!("business_hours/schedules/{}/holidays.json", schedule_id);
+        let response: HolidaysResponse = self.get(&endpoint).await?;
+        Ok(response.holidays)
+    }
+
+    pub async fn get_holiday(&self, schedule_id: u64, holiday_id: u64) -> Result<Holiday> {
+        let endpoint = format!(
+            "business_hours/schedules/{}/holidays/{}.json",
+            schedule_id, holiday_id
+        );
+        let response: HolidayResponse = self.get(&endpoint).await?;
+        Ok(response.holiday)
+    }
+
+    pub async fn create_holiday(&self, schedule_id: u64, holiday: Holiday) -> Result<Holiday> {
+        let endpoint = format!("business_hours/schedules/{}/holidays.json", schedule_id);
+        let request = HolidayRequest { holiday };
+        let response: HolidayResponse = self.post(&endpoint, &request).await?;
+        Ok(response.holiday)
+    }
+
+    pub async fn update_holiday(
+        &self,
+        schedule_id: u64,
+        holiday_id: u64,
+        holiday: Holiday,
+    ) -> Result<Holiday> {
+        let endpoint = format!(
+            "business_hours/schedules/{}/holidays/{}.json",
+            schedule_id, holiday_id
+        );
+        let request = HolidayRequest { holiday };
+        let response: HolidayResponse = self.put(&endpoint, &request).await?;
+        Ok(response.holiday)
+    }
+
+    pub async fn delete_holiday(&self, schedule_id: u64, holiday_id: u64) -> Result<()> {
+        let endpoint = format!(
+            "business_hours/schedules/{}/holidays/{}.json",
+            schedule_id, holiday_id
+        );
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Fetch a schedule and its holidays as a [`BusinessCalendar`] for computing business
+    /// time locally
+    pub async fn get_business_calendar(&self, schedule_id: u64) -> Result<BusinessCalendar> {
+        let schedule = self.get_schedule(schedule_id).await?;
+        let holidays = self.list_holidays(schedule_id).await?;
+        BusinessCalendar::new(&schedule, &holidays)
+    }
+}
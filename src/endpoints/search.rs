@@ -1,9 +1,104 @@
 use crate::client::ZendeskClient;
 use crate::errors::Result;
 use crate::models::search::{
-    SearchCountResponse, SearchExportResponse, SearchQueryBuilder, SearchResponse, SearchSortBy,
+    SearchCountResponse, SearchExportResponse, SearchQueryBuilder, SearchResponse, SearchResult,
+    SearchResultType, SearchSortBy,
 };
 use crate::query::SortOrder;
+use std::collections::{HashSet, VecDeque};
+
+/// Maximum number of results the offset-paginated `search` endpoint will return
+pub const SEARCH_OFFSET_LIMIT: u64 = 1000;
+
+/// Page size used when paging through `search/export`
+const SEARCH_EXPORT_PAGE_SIZE: u32 = 1000;
+
+enum SearchSource {
+    Offset { next_page: Option<String> },
+    Export { cursor: Option<String> },
+}
+
+/// A single stream of search results that pages through `search` or `search/export`
+/// as needed, skipping records already yielded by an earlier page
+pub struct SearchResultStream<'a> {
+    client: &'a ZendeskClient,
+    query: String,
+    result_type: SearchResultType,
+    total_count: u64,
+    source: SearchSource,
+    started: bool,
+    buffer: VecDeque<SearchResult>,
+    seen: HashSet<(SearchResultType, u64)>,
+}
+
+impl<'a> SearchResultStream<'a> {
+    /// Result count reported by `search/count` when the stream was opened
+    pub fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    /// Whether the stream is backed by `search/export` rather than offset search
+    pub fn uses_export(&self) -> bool {
+        matches!(self.source, SearchSource::Export { .. })
+    }
+
+    /// Fetch the next result, requesting further pages as the buffer drains
+    pub async fn try_next(&mut self) -> Result<Option<SearchResult>> {
+        loop {
+            while let Some(result) = self.buffer.pop_front() {
+                match result.identity() {
+                    Some(key) if !self.seen.insert(key) => continue,
+                    _ => return Ok(Some(result)),
+                }
+            }
+
+            if !self.fetch_next_page().await? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Drain the stream into a vector
+    pub async fn collect_all(mut self) -> Result<Vec<SearchResult>> {
+        let mut results = Vec::new();
+        while let Some(result) = self.try_next().await? {
+            results.push(result);
+        }
+        Ok(results)
+    }
+
+    async fn fetch_next_page(&mut self) -> Result<bool> {
+        let first_page = !self.started;
+        self.started = true;
+
+        match &mut self.source {
+            SearchSource::Offset { next_page } => {
+                let response = match (first_page, next_page.take()) {
+                    (true, _) => self.client.search(&self.query).await?,
+                    (false, Some(page_url)) => {
+                        self.client.search_with_pagination(&page_url).await?
+                    }
+                    (false, None) => return Ok(false),
+                };
+                *next_page = response.next_page;
+                self.buffer.extend(response.results);
+            }
+            SearchSource::Export { cursor } => {
+                if !first_page && cursor.is_none() {
+                    return Ok(false);
+                }
+                let response = self
+                    .client
+                    .search_export_page(&self.query, self.result_type, cursor.as_deref())
+                    .await?;
+                *cursor = response.next_cursor().map(str::to_string);
+                self.buffer.extend(response.results);
+            }
+        }
+
+        Ok(true)
+    }
+}
 
 impl ZendeskClient {
     /// Search across tickets, users, organizations, and groups
@@ -72,6 +167,58 @@ impl ZendeskClient {
         self.get(&endpoint).await
     }
 
+    /// Fetch a single page of `search/export` results for one resource type
+    pub async fn search_export_page(
+        &self,
+        query: &str,
+        result_type: SearchResultType,
+        cursor: Option<&str>,
+    ) -> Result<SearchExportResponse> {
+        let mut endpoint = format!(
+            "search/export.json?query={}&filter[type]={}&page[size]={}",
+            urlencoding::encode(query),
+            result_type,
+            SEARCH_EXPORT_PAGE_SIZE
+        );
+
+        if let Some(cursor_value) = cursor {
+            endpoint.push_str(&format!(
+                "&page[after]={}",
+                urlencoding::encode(cursor_value)
+            ));
+        }
+
+        self.get(&endpoint).await
+    }
+
+    /// Search all results of one resource type, falling back to `search/export`
+    /// when `search/count` reports more results than offset search can return
+    pub async fn search_all(
+        &self,
+        query: &str,
+        result_type: SearchResultType,
+    ) -> Result<SearchResultStream<'_>> {
+        let query = result_type.scope_query(query);
+        let total_count = self.search_count(&query).await?;
+
+        let source = if total_count > SEARCH_OFFSET_LIMIT {
+            SearchSource::Export { cursor: None }
+        } else {
+            SearchSource::Offset { next_page: None }
+        };
+
+        Ok(SearchResultStream {
+            client: self,
+            query,
+            result_type,
+            total_count,
+            source,
+            started: false,
+            buffer: VecDeque::new(),
+            seen: HashSet::new(),
+        })
+    }
+
     // Convenience methods for specific resource types
 
     /// Search only tickets (returns SearchResponse with full search metadata)
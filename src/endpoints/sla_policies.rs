@@ -0,0 +1,51 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::sla_policy::{
+    SlaPoliciesResponse, SlaPolicy, SlaPolicyReorderRequest, SlaPolicyRequest, SlaPolicyResponse,
+    SlaPolicyUpsert,
+};
+
+impl ZendeskClient {
+    pub async fn list_sla_policies(&self) -> Result<Vec<SlaPolicy>> {
+        let response: SlaPoliciesResponse = self.get("slas/policies.json").await?;
+        Ok(response.sla_policies)
+    }
+
+    pub async fn get_sla_policy(&self, sla_policy_id: u64) -> Result<SlaPolicy> {
+        let endpoint = format!("slas/policies/{}.json", sla_policy_id);
+        let response: SlaPolicyResponse = self.get(&endpoint).await?;
+        Ok(response.sla_policy)
+    }
+
+    pub async fn create_sla_policy(&self, sla_policy: SlaPolicyUpsert) -> Result<SlaPolicy> {
+        let request = SlaPolicyRequest { sla_policy };
+        let response: SlaPolicyResponse = self.post("slas/policies.json", &request).await?;
+        Ok(response.sla_policy)
+    }
+
+    /// Update an SLA policy. Zendesk replaces `filter` and `policy_metrics` wholesale,
+    /// so send the full lists when changing either.
+    pub async fn update_sla_policy(
+        &self,
+        sla_policy_id: u64,
+        sla_policy: SlaPolicyUpsert,
+    ) -> Result<SlaPolicy> {
+        let endpoint = format!("slas/policies/{}.json", sla_policy_id);
+        let request = SlaPolicyRequest { sla_policy };
+        let response: SlaPolicyResponse = self.put(&endpoint, &request).await?;
+        Ok(response.sla_policy)
+    }
+
+    pub async fn delete_sla_policy(&self, sla_policy_id: u64) -> Result<()> {
+        let endpoint = format!("slas/policies/{}.json", sla_policy_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Set the order in which SLA policies are matched; `sla_policy_ids` lists them first to last
+    pub async fn reorder_sla_policies(&self, sla_policy_ids: Vec<u64>) -> Result<()> {
+        let request = SlaPolicyReorderRequest { sla_policy_ids };
+        let _: serde_json::Value = self.put("slas/policies/reorder.json", &request).await?;
+        Ok(())
+    }
+}
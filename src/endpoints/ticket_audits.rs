@@ -0,0 +1,76 @@
+use crate::client::ZendeskClient;
+use crate::errors::{Result, ZendeskError};
+use crate::models::ticket::TicketComment;
+use crate::models::ticket_audit::{TicketAudit, TicketAuditResponse, TicketAuditsResponse};
+use crate::query::QueryParams;
+
+impl ZendeskClient {
+    /// List the audits of a single ticket, oldest first
+    pub async fn list_ticket_audits(&self, ticket_id: u64) -> Result<Vec<TicketAudit>> {
+        let endpoint = format!("tickets/{}/audits.json", ticket_id);
+        let response: TicketAuditsResponse = self.get(&endpoint).await?;
+        Ok(response.audits)
+    }
+
+    /// List a ticket's audits with query parameters (pagination, sorting, etc.)
+    pub async fn list_ticket_audits_with_params(
+        &self,
+        ticket_id: u64,
+        params: &QueryParams,
+    ) -> Result<TicketAuditsResponse> {
+        let endpoint = format!("tickets/{}/audits.json", ticket_id);
+        self.get_with_params(&endpoint, params).await
+    }
+
+    pub async fn get_ticket_audit(&self, ticket_id: u64, audit_id: u64) -> Result<TicketAudit> {
+        let endpoint = format!("tickets/{}/audits/{}.json", ticket_id, audit_id);
+        let response: TicketAuditResponse = self.get(&endpoint).await?;
+        Ok(response.audit)
+    }
+
+    /// Fetch the audit that created a comment, using the comment's `audit_id`
+    pub async fn get_audit_for_comment(&self, comment: &TicketComment) -> Result<TicketAudit> {
+        let (Some(ticket_id), Some(audit_id)) = (comment.ticket_id, comment.audit_id) else {
+            return Err(ZendeskError::validation(
+                "Comment must have both ticket_id and audit_id",
+            ));
+        };
+        self.get_ticket_audit(ticket_id, audit_id).await
+    }
+
+    pub async fn count_ticket_audits(&self, ticket_id: u64) -> Result<u64> {
+        let endpoint = format!("tickets/{}/audits/count.json", ticket_id);
+        let response: serde_json::Value = self.get(&endpoint).await?;
+        response
+            .get("count")
+            .and_then(|count| count.get("value"))
+            .and_then(|value| value.as_u64())
+            .ok_or_else(|| {
+                ZendeskError::UnexpectedResponse("Count not found in response".to_string())
+            })
+    }
+
+    /// List audits across all tickets using cursor pagination (admins only)
+    pub async fn list_all_ticket_audits(
+        &self,
+        cursor: Option<&str>,
+        page_size: Option<u32>,
+    ) -> Result<TicketAuditsResponse> {
+        let mut endpoint = "ticket_audits.json".to_string();
+        let mut params = Vec::new();
+
+        if let Some(cursor_val) = cursor {
+            params.push(format!("page[after]={}", urlencoding::encode(cursor_val)));
+        }
+        if let Some(size) = page_size {
+            params.push(format!("page[size]={}", size));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        self.get(&endpoint).await
+    }
+}
@@ -0,0 +1,186 @@
+use crate::client::ZendeskClient;
+use crate::endpoints::tickets::{BULK_CHUNK_SIZE, join_ids};
+use crate::errors::Result;
+use crate::models::custom_object::CustomFieldOption;
+use crate::models::ticket_field::{
+    CustomFieldOptionRequest, CustomFieldOptionResponse, CustomFieldOptionsResponse, TicketField,
+    TicketFieldCreate, TicketFieldCreateRequest, TicketFieldResolver, TicketFieldResponse,
+    TicketFieldUpdate, TicketFieldUpdateRequest, TicketFieldsResponse, TicketForm,
+    TicketFormReorderRequest, TicketFormRequest, TicketFormResponse, TicketFormUpsert,
+    TicketFormsResponse,
+};
+
+impl ZendeskClient {
+    // Ticket fields
+
+    /// List all ticket fields, following pagination
+    pub async fn list_ticket_fields(&self) -> Result<Vec<TicketField>> {
+        let mut response: TicketFieldsResponse = self.get("ticket_fields.json").await?;
+        let mut fields = std::mem::take(&mut response.ticket_fields);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            fields.append(&mut response.ticket_fields);
+        }
+
+        Ok(fields)
+    }
+
+    pub async fn get_ticket_field(&self, field_id: u64) -> Result<TicketField> {
+        let endpoint = format!("ticket_fields/{}.json", field_id);
+        let response: TicketFieldResponse = self.get(&endpoint).await?;
+        Ok(response.ticket_field)
+    }
+
+    pub async fn create_ticket_field(&self, field: TicketFieldCreate) -> Result<TicketField> {
+        let request = TicketFieldCreateRequest {
+            ticket_field: field,
+        };
+        let response: TicketFieldResponse = self.post("ticket_fields.json", &request).await?;
+        Ok(response.ticket_field)
+    }
+
+    pub async fn update_ticket_field(
+        &self,
+        field_id: u64,
+        update: TicketFieldUpdate,
+    ) -> Result<TicketField> {
+        let endpoint = format!("ticket_fields/{}.json", field_id);
+        let request = TicketFieldUpdateRequest {
+            ticket_field: update,
+        };
+        let response: TicketFieldResponse = self.put(&endpoint, &request).await?;
+        Ok(response.ticket_field)
+    }
+
+    pub async fn delete_ticket_field(&self, field_id: u64) -> Result<()> {
+        let endpoint = format!("ticket_fields/{}.json", field_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    pub async fn list_ticket_field_options(&self, field_id: u64) -> Result<Vec<CustomFieldOption>> {
+        let endpoint = format!("ticket_fields/{}/options.json", field_id);
+        let response: CustomFieldOptionsResponse = self.get(&endpoint).await?;
+        Ok(response.custom_field_options)
+    }
+
+    /// Create an option, or update it when `option.id` is set
+    pub async fn upsert_ticket_field_option(
+        &self,
+        field_id: u64,
+        option: CustomFieldOption,
+    ) -> Result<CustomFieldOption> {
+        let endpoint = format!("ticket_fields/{}/options.json", field_id);
+        let request = CustomFieldOptionRequest {
+            custom_field_option: option,
+        };
+        let response: CustomFieldOptionResponse = self.post(&endpoint, &request).await?;
+        Ok(response.custom_field_option)
+    }
+
+    pub async fn delete_ticket_field_option(&self, field_id: u64, option_id: u64) -> Result<()> {
+        let endpoint = format!("ticket_fields/{}/options/{}.json", field_id, option_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Load every ticket field definition into a resolver for typed custom field values
+    pub async fn ticket_field_resolver(&self) -> Result<TicketFieldResolver> {
+        let fields = self.list_ticket_fields().await?;
+        Ok(TicketFieldResolver::new(fields))
+    }
+
+    // Ticket forms
+
+    pub async fn list_ticket_forms(&self, active_only: bool) -> Result<Vec<TicketForm>> {
+        let endpoint = if active_only {
+            "ticket_forms.json?active=true"
+        } else {
+            "ticket_forms.json"
+        };
+        let response: TicketFormsResponse = self.get(endpoint).await?;
+        Ok(response.ticket_forms)
+    }
+
+    pub async fn get_ticket_form(&self, form_id: u64) -> Result<TicketForm> {
+        let endpoint = format!("ticket_forms/{}.json", form_id);
+        let response: TicketFormResponse = self.get(&endpoint).await?;
+        Ok(response.ticket_form)
+    }
+
+    /// Fetch ticket forms by id through `ticket_forms/show_many`, in chunks of 100
+    pub async fn get_many_ticket_forms(&self, form_ids: &[u64]) -> Result<Vec<TicketForm>> {
+        let mut forms = Vec::with_capacity(form_ids.len());
+        for chunk in form_ids.chunks(BULK_CHUNK_SIZE) {
+            let endpoint = format!("ticket_forms/show_many.json?ids={}", join_ids(chunk));
+            let response: TicketFormsResponse = self.get(&endpoint).await?;
+            forms.extend(response.ticket_forms);
+        }
+        Ok(forms)
+    }
+
+    pub async fn create_ticket_form(&self, form: TicketFormUpsert) -> Result<TicketForm> {
+        let request = TicketFormRequest { ticket_form: form };
+        let response: TicketFormResponse = self.post("ticket_forms.json", &request).await?;
+        Ok(response.ticket_form)
+    }
+
+    pub async fn update_ticket_form(
+        &self,
+        form_id: u64,
+        form: TicketFormUpsert,
+    ) -> Result<TicketForm> {
+        let endpoint = format!("ticket_forms/{}.json", form_id);
+        let request = TicketFormRequest { ticket_form: form };
+        let response: TicketFormResponse = self.put(&endpoint, &request).await?;
+        Ok(response.ticket_form)
+    }
+
+    pub async fn delete_ticket_form(&self, form_id: u64) -> Result<()> {
+        let endpoint = format!("ticket_forms/{}.json", form_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    pub async fn clone_ticket_form(&self, form_id: u64) -> Result<TicketForm> {
+        let endpoint = format!("ticket_forms/{}/clone.json", form_id);
+        let response: TicketFormResponse = self.post(&endpoint, &serde_json::json!({})).await?;
+        Ok(response.ticket_form)
+    }
+
+    /// Set the display order of ticket forms; `form_ids` must list every form
+    pub async fn reorder_ticket_forms(&self, form_ids: Vec<u64>) -> Result<Vec<TicketForm>> {
+        let request = TicketFormReorderRequest {
+            ticket_form_ids: form_ids,
+        };
+        let response: TicketFormsResponse = self.put("ticket_forms/reorder.json", &request).await?;
+        Ok(response.ticket_forms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::mock_client;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_many_ticket_forms_chunks_ids() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/ticket_forms/show_many.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ticket_forms": [{ "id": 1, "name": "Default" }]
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let ids: Vec<u64> = (1..=150).collect();
+        let forms = client.get_many_ticket_forms(&ids).await.unwrap();
+        assert_eq!(forms.len(), 2);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::client::ZendeskClient;
+use crate::endpoints::job_statuses::submit_in_chunks;
+use crate::endpoints::tickets::BULK_CHUNK_SIZE;
+use crate::errors::{Result, ZendeskError};
+use crate::models::job_status::{BulkJobResponse, JobStatus};
+use crate::models::ticket::{Ticket, TicketResponse};
+use crate::models::ticket_import::{TicketImport, TicketImportManyRequest, TicketImportRequest};
+
+fn validate_import(ticket: &TicketImport) -> Result<()> {
+    if ticket.description.is_none() && ticket.comments.is_empty() {
+        return Err(ZendeskError::validation(format!(
+            "Imported ticket '{}' needs a description or at least one comment",
+            ticket.subject
+        )));
+    }
+    Ok(())
+}
+
+fn import_endpoint(path: &str, archive_immediately: bool) -> String {
+    if archive_immediately {
+        format!("{}?archive_immediately=true", path)
+    } else {
+        path.to_string()
+    }
+}
+
+impl ZendeskClient {
+    /// Import a single historical ticket. With `archive_immediately`, closed tickets skip
+    /// the active ticket store and go straight to the archive.
+    pub async fn import_ticket(
+        &self,
+        ticket: TicketImport,
+        archive_immediately: bool,
+    ) -> Result<Ticket> {
+        validate_import(&ticket)?;
+
+        let endpoint = import_endpoint("imports/tickets.json", archive_immediately);
+        let request = TicketImportRequest { ticket };
+        let response: TicketResponse = self.post(&endpoint, &request).await?;
+        Ok(response.ticket)
+    }
+
+    /// Import many historical tickets, split into batches of 100. Returns one job status
+    /// per batch.
+    pub async fn import_many_tickets(
+        &self,
+        tickets: Vec<TicketImport>,
+        archive_immediately: bool,
+    ) -> Result<Vec<JobStatus>> {
+        for ticket in &tickets {
+            validate_import(ticket)?;
+        }
+
+        let endpoint = import_endpoint("imports/tickets/create_many.json", archive_immediately);
+        let endpoint = &endpoint;
+        submit_in_chunks(&tickets, BULK_CHUNK_SIZE, |chunk| async move {
+            let request = TicketImportManyRequest {
+                tickets: chunk.to_vec(),
+            };
+            let response: BulkJobResponse = self.post(endpoint, &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+}
@@ -0,0 +1,32 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::ticket_metric::{TicketMetric, TicketMetricResponse, TicketMetricsResponse};
+use crate::query::QueryParams;
+
+impl ZendeskClient {
+    pub async fn list_ticket_metrics(&self) -> Result<Vec<TicketMetric>> {
+        let response: TicketMetricsResponse = self.get("ticket_metrics.json").await?;
+        Ok(response.ticket_metrics)
+    }
+
+    /// List ticket metrics with query parameters (pagination, etc.)
+    pub async fn list_ticket_metrics_with_params(
+        &self,
+        params: &QueryParams,
+    ) -> Result<TicketMetricsResponse> {
+        self.get_with_params("ticket_metrics.json", params).await
+    }
+
+    pub async fn get_ticket_metric(&self, ticket_metric_id: u64) -> Result<TicketMetric> {
+        let endpoint = format!("ticket_metrics/{}.json", ticket_metric_id);
+        let response: TicketMetricResponse = self.get(&endpoint).await?;
+        Ok(response.ticket_metric)
+    }
+
+    /// Fetch the metrics of a single ticket
+    pub async fn get_ticket_metrics_for_ticket(&self, ticket_id: u64) -> Result<TicketMetric> {
+        let endpoint = format!("tickets/{}/metrics.json", ticket_id);
+        let response: TicketMetricResponse = self.get(&endpoint).await?;
+        Ok(response.ticket_metric)
+    }
+}
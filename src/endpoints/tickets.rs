@@ -1,12 +1,28 @@
 use crate::client::ZendeskClient;
-use crate::errors::Result;
+use crate::endpoints::job_statuses::submit_in_chunks;
+use crate::errors::{Result, ZendeskError};
+use crate::models::job_status::{BulkJobResponse, JobStatus};
 use crate::models::ticket::{
-    Ticket, TicketComment, TicketCommentCountResponse, TicketCommentCreate, TicketCommentRequest,
-    TicketCommentsResponse, TicketCreateRequest, TicketResponse, TicketsResponse,
+    Ticket, TicketBatchUpdateRequest, TicketBulkUpdate, TicketChanges, TicketChangesRequest,
+    TicketComment, TicketCommentCountResponse, TicketCommentCreate, TicketCommentRequest,
+    TicketCommentsResponse, TicketCreate, TicketCreateManyRequest, TicketCreateRequest,
+    TicketMergeRequest, TicketParticipantChange, TicketResponse, TicketType,
+    TicketUpdateManyRequest, TicketsResponse,
 };
+use crate::models::user::{User, UsersResponse};
 use crate::query::QueryParams;
 use crate::query::{SideloadedResponse, TicketsWithSideloading};
 
+/// Maximum number of records Zendesk accepts in a single bulk request
+pub(crate) const BULK_CHUNK_SIZE: usize = 100;
+
+pub(crate) fn join_ids(ids: &[u64]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 impl ZendeskClient {
     pub async fn create_ticket(&self, ticket_request: TicketCreateRequest) -> Result<Ticket> {
         let response: TicketResponse = self.post("tickets.json", &ticket_request).await?;
@@ -29,6 +45,17 @@ impl ZendeskClient {
         self.get_with_sideloading(&endpoint, include).await
     }
 
+    /// Fetch tickets by id through `tickets/show_many`, in chunks of 100
+    pub async fn get_many_tickets(&self, ticket_ids: &[u64]) -> Result<Vec<Ticket>> {
+        let mut tickets = Vec::with_capacity(ticket_ids.len());
+        for chunk in ticket_ids.chunks(BULK_CHUNK_SIZE) {
+            let endpoint = format!("tickets/show_many.json?ids={}", join_ids(chunk));
+            let response: TicketsResponse = self.get(&endpoint).await?;
+            tickets.extend(response.tickets);
+        }
+        Ok(tickets)
+    }
+
     pub async fn update_ticket(
         &self,
         ticket_id: u64,
@@ -39,12 +66,258 @@ impl ZendeskClient {
         Ok(response.ticket)
     }
 
+    /// Write `changes` to a single ticket, with or without a comment
+    pub async fn update_ticket_changes(
+        &self,
+        ticket_id: u64,
+        changes: TicketChanges,
+    ) -> Result<Ticket> {
+        let endpoint = format!("tickets/{}.json", ticket_id);
+        let request = TicketChangesRequest { ticket: changes };
+        let response: TicketResponse = self.put(&endpoint, &request).await?;
+        Ok(response.ticket)
+    }
+
     pub async fn delete_ticket(&self, ticket_id: u64) -> Result<()> {
         let endpoint = format!("tickets/{}.json", ticket_id);
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
 
+    // Bulk ticket operations. Zendesk accepts at most 100 tickets per request, so larger
+    // inputs are split into chunks and one job status is returned per chunk. If a chunk
+    // fails, the jobs already submitted come back in `ZendeskError::Bulk`.
+
+    /// Create many tickets via `tickets/create_many`
+    pub async fn create_many_tickets(&self, tickets: Vec<TicketCreate>) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(&tickets, BULK_CHUNK_SIZE, |chunk| async move {
+            let request = TicketCreateManyRequest {
+                tickets: chunk.to_vec(),
+            };
+            let response: BulkJobResponse = self.post("tickets/create_many.json", &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Apply the same change to many tickets via `tickets/update_many?ids=...`. The update
+    /// must not carry an `id`; use `update_many_tickets_individually` for per-ticket changes.
+    pub async fn update_many_tickets(
+        &self,
+        ticket_ids: &[u64],
+        update: TicketBulkUpdate,
+    ) -> Result<Vec<JobStatus>> {
+        if update.id.is_some() {
+            return Err(ZendeskError::validation(
+                "A shared ticket update cannot have an id; the tickets are given by ticket_ids",
+            ));
+        }
+
+        let update = &update;
+        submit_in_chunks(ticket_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!("tickets/update_many.json?ids={}", join_ids(chunk));
+            let request = TicketUpdateManyRequest {
+                ticket: update.clone(),
+            };
+            let response: BulkJobResponse = self.put(&endpoint, &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Apply per-ticket changes via `tickets/update_many`; each update must carry an `id`
+    pub async fn update_many_tickets_individually(
+        &self,
+        updates: Vec<TicketBulkUpdate>,
+    ) -> Result<Vec<JobStatus>> {
+        if updates.iter().any(|update| update.id.is_none()) {
+            return Err(ZendeskError::validation(
+                "Every ticket in a batch update must have an id",
+            ));
+        }
+
+        submit_in_chunks(&updates, BULK_CHUNK_SIZE, |chunk| async move {
+            let request = TicketBatchUpdateRequest {
+                tickets: chunk.to_vec(),
+            };
+            let response: BulkJobResponse = self.put("tickets/update_many.json", &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Delete many tickets via `tickets/destroy_many`
+    pub async fn destroy_many_tickets(&self, ticket_ids: &[u64]) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(ticket_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!("tickets/destroy_many.json?ids={}", join_ids(chunk));
+            let response: BulkJobResponse = self.delete(&endpoint).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Mark many tickets as spam and suspend their requesters via `tickets/mark_many_as_spam`
+    pub async fn mark_many_tickets_as_spam(&self, ticket_ids: &[u64]) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(ticket_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!("tickets/mark_many_as_spam.json?ids={}", join_ids(chunk));
+            let response: BulkJobResponse = self.put(&endpoint, &serde_json::json!({})).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    // Collaborators, followers and email CCs
+
+    /// List a ticket's collaborators (followers and CCs combined)
+    pub async fn list_ticket_collaborators(&self, ticket_id: u64) -> Result<Vec<User>> {
+        let endpoint = format!("tickets/{}/collaborators.json", ticket_id);
+        let response: UsersResponse = self.get(&endpoint).await?;
+        Ok(response.users)
+    }
+
+    pub async fn list_ticket_followers(&self, ticket_id: u64) -> Result<Vec<User>> {
+        let endpoint = format!("tickets/{}/followers.json", ticket_id);
+        let response: UsersResponse = self.get(&endpoint).await?;
+        Ok(response.users)
+    }
+
+    pub async fn list_ticket_email_ccs(&self, ticket_id: u64) -> Result<Vec<User>> {
+        let endpoint = format!("tickets/{}/email_ccs.json", ticket_id);
+        let response: UsersResponse = self.get(&endpoint).await?;
+        Ok(response.users)
+    }
+
+    /// Add or remove email CCs and followers without adding a comment
+    pub async fn update_ticket_participants(
+        &self,
+        ticket_id: u64,
+        email_ccs: Vec<TicketParticipantChange>,
+        followers: Vec<TicketParticipantChange>,
+    ) -> Result<Ticket> {
+        let mut changes = TicketChanges::new();
+        if !email_ccs.is_empty() {
+            changes = changes.email_ccs(email_ccs);
+        }
+        if !followers.is_empty() {
+            changes = changes.followers(followers);
+        }
+        self.update_ticket_changes(ticket_id, changes).await
+    }
+
+    // Merging and problem/incident linkage
+
+    /// Merge the source tickets listed in `request` into `target_ticket_id`
+    pub async fn merge_tickets(
+        &self,
+        target_ticket_id: u64,
+        request: TicketMergeRequest,
+    ) -> Result<JobStatus> {
+        if request.ids.is_empty() {
+            return Err(ZendeskError::validation(
+                "At least one source ticket is required to merge",
+            ));
+        }
+        if request.ids.contains(&target_ticket_id) {
+            return Err(ZendeskError::validation(
+                "A ticket cannot be merged into itself",
+            ));
+        }
+
+        let endpoint = format!("tickets/{}/merge.json", target_ticket_id);
+        let response: BulkJobResponse = self.post(&endpoint, &request).await?;
+        Ok(response.job_status)
+    }
+
+    /// List problem tickets
+    pub async fn list_problems(&self) -> Result<Vec<Ticket>> {
+        let response: TicketsResponse = self.get("problems.json").await?;
+        Ok(response.tickets)
+    }
+
+    /// List the incidents linked to a problem ticket
+    pub async fn list_problem_incidents(&self, problem_id: u64) -> Result<Vec<Ticket>> {
+        let endpoint = format!("tickets/{}/incidents.json", problem_id);
+        let response: TicketsResponse = self.get(&endpoint).await?;
+        Ok(response.tickets)
+    }
+
+    /// Get the problem ticket an incident is linked to, if any
+    pub async fn get_linked_problem(&self, incident_id: u64) -> Result<Option<Ticket>> {
+        let incident = self.get_ticket(incident_id).await?;
+        match incident.problem_id {
+            Some(problem_id) => Ok(Some(self.get_ticket(problem_id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Make a ticket an incident of `problem_id`, after checking the problem really is a
+    /// problem ticket and the incident is not one
+    pub async fn link_incident_to_problem(
+        &self,
+        incident_id: u64,
+        problem_id: u64,
+    ) -> Result<Ticket> {
+        self.ensure_problem_ticket(problem_id).await?;
+
+        let incident = self.get_ticket(incident_id).await?;
+        if incident.ticket_type == Some(TicketType::Problem) {
+            return Err(ZendeskError::validation(format!(
+                "Ticket {} is a problem and cannot be linked as an incident",
+                incident_id
+            )));
+        }
+
+        let changes = TicketChanges::new()
+            .ticket_type(TicketType::Incident)
+            .problem_id(problem_id);
+        self.update_ticket_changes(incident_id, changes).await
+    }
+
+    /// Link many incidents to a problem ticket in chunked bulk updates. Fails before any
+    /// update if one of the incidents is itself a problem ticket.
+    pub async fn link_incidents_to_problem(
+        &self,
+        incident_ids: &[u64],
+        problem_id: u64,
+    ) -> Result<Vec<JobStatus>> {
+        self.ensure_problem_ticket(problem_id).await?;
+        if incident_ids.contains(&problem_id) {
+            return Err(ZendeskError::validation(
+                "A problem ticket cannot be its own incident",
+            ));
+        }
+
+        let problems: Vec<String> = self
+            .get_many_tickets(incident_ids)
+            .await?
+            .into_iter()
+            .filter(|ticket| ticket.ticket_type == Some(TicketType::Problem))
+            .filter_map(|ticket| ticket.id.map(|id| id.to_string()))
+            .collect();
+        if !problems.is_empty() {
+            return Err(ZendeskError::validation(format!(
+                "Tickets {} are problems and cannot be linked as incidents",
+                problems.join(", ")
+            )));
+        }
+
+        let update = TicketBulkUpdate::new()
+            .ticket_type(TicketType::Incident)
+            .problem_id(problem_id);
+        self.update_many_tickets(incident_ids, update).await
+    }
+
+    async fn ensure_problem_ticket(&self, problem_id: u64) -> Result<Ticket> {
+        let problem = self.get_ticket(problem_id).await?;
+        if problem.ticket_type != Some(TicketType::Problem) {
+            return Err(ZendeskError::validation(format!(
+                "Ticket {} is not a problem ticket",
+                problem_id
+            )));
+        }
+        Ok(problem)
+    }
+
     pub async fn list_tickets(&self) -> Result<Vec<Ticket>> {
         let response: TicketsResponse = self.get("tickets.json").await?;
         Ok(response.tickets)
@@ -129,9 +402,7 @@ impl ZendeskClient {
                 if let Some(path_start) = page_url.find("/api/v2/") {
                     &page_url[path_start + 8..] // Skip "/api/v2/"
                 } else {
-                    return Err(crate::errors::ZendeskError::InvalidUrl(
-                        page_url.to_string(),
-                    ));
+                    return Err(ZendeskError::InvalidUrl(page_url.to_string()));
                 }
             }
             None => &format!("tickets/{}/comments.json", ticket_id),
@@ -160,7 +431,7 @@ impl ZendeskClient {
         if let Some(comment) = response.get("comment") {
             Ok(serde_json::from_value(comment.clone())?)
         } else {
-            Err(crate::errors::ZendeskError::UnexpectedResponse(
+            Err(ZendeskError::UnexpectedResponse(
                 "Comment not found in response".to_string(),
             ))
         }
@@ -196,6 +467,8 @@ impl ZendeskClient {
                 tags: None,
                 additional_tags: None,
                 remove_tags: None,
+                email_ccs: None,
+                followers: None,
             },
         };
         self.add_ticket_comment(ticket_id, request).await
@@ -214,6 +487,8 @@ impl ZendeskClient {
                 tags: None,
                 additional_tags: None,
                 remove_tags: None,
+                email_ccs: None,
+                followers: None,
             },
         };
         self.add_ticket_comment(ticket_id, request).await
@@ -238,6 +513,8 @@ impl ZendeskClient {
                 tags,
                 additional_tags: None,
                 remove_tags: None,
+                email_ccs: None,
+                followers: None,
             },
         };
         self.add_ticket_comment(ticket_id, request).await
@@ -260,6 +537,8 @@ impl ZendeskClient {
                 tags: None,
                 additional_tags: None,
                 remove_tags: None,
+                email_ccs: None,
+                followers: None,
             },
         };
         self.add_ticket_comment(ticket_id, request).await
@@ -283,6 +562,8 @@ impl ZendeskClient {
                 tags: None,
                 additional_tags: None,
                 remove_tags: None,
+                email_ccs: None,
+                followers: None,
             },
         };
         self.add_ticket_comment(ticket_id, request).await
@@ -349,6 +630,7 @@ impl ZendeskClient {
     ) -> Result<Ticket> {
         let comment = TicketCommentCreate {
             body: comment_body.into(),
+            html_body: None,
             public: Some(is_public),
             author_id: None,
             uploads: None,
@@ -363,12 +645,15 @@ impl ZendeskClient {
                 tags: replace_tags,
                 additional_tags: add_tags,
                 remove_tags,
+                email_ccs: None,
+                followers: None,
             },
         };
         self.add_ticket_comment(ticket_id, request).await
     }
 
     /// Update ticket properties without adding a comment
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_ticket_properties(
         &self,
         ticket_id: u64,
@@ -420,3 +705,251 @@ impl ZendeskClient {
         Ok(response.ticket)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock_client;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn job_response(id: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200)
+            .set_body_json(json!({ "job_status": { "id": id, "status": "queued" } }))
+    }
+
+    #[tokio::test]
+    async fn test_update_many_tickets_chunks_ids() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v2/tickets/update_many.json"))
+            .respond_with(job_response("job"))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let ids: Vec<u64> = (1..=250).collect();
+        let update = TicketBulkUpdate::new().tags(vec!["bulk".to_string()]);
+        let jobs = client.update_many_tickets(&ids, update).await.unwrap();
+        assert_eq!(jobs.len(), 3);
+
+        let requests = server.received_requests().await.unwrap();
+        let chunk_sizes: Vec<usize> = requests
+            .iter()
+            .map(|request| {
+                let (_, ids) = request
+                    .url
+                    .query_pairs()
+                    .find(|(key, _)| key == "ids")
+                    .unwrap();
+                ids.split(',').count()
+            })
+            .collect();
+        assert_eq!(chunk_sizes, vec![100, 100, 50]);
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body, json!({ "ticket": { "tags": ["bulk"] } }));
+    }
+
+    #[tokio::test]
+    async fn test_update_many_tickets_reports_submitted_jobs() {
+        let server = MockServer::start().await;
+        let second_chunk: Vec<u64> = (101..=200).collect();
+        Mock::given(method("PUT"))
+            .and(path("/api/v2/tickets/update_many.json"))
+            .and(query_param("ids", join_ids(&second_chunk)))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({ "error": "Boom" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v2/tickets/update_many.json"))
+            .respond_with(job_response("first"))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let ids: Vec<u64> = (1..=250).collect();
+        let update = TicketBulkUpdate::new().status(crate::models::ticket::TicketStatus::Solved);
+        let error = client.update_many_tickets(&ids, update).await.unwrap_err();
+
+        let submitted = error.submitted_jobs();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].id.as_deref(), Some("first"));
+        assert!(matches!(
+            error,
+            ZendeskError::Bulk { source, .. } if matches!(*source, ZendeskError::Api { status: 500, .. })
+        ));
+        // The failing chunk stops the run, so the third chunk is never sent
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    fn ticket_response(id: u64, ticket_type: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(json!({
+            "ticket": { "id": id, "subject": "Printer", "description": "Broken", "type": ticket_type }
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_merge_tickets_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v2/tickets/5/merge.json"))
+            .and(body_json(json!({
+                "ids": [6, 7],
+                "source_comment": "Closing in favour of #5",
+                "source_comment_is_public": false,
+                "target_comment": "Merged #6 and #7",
+                "target_comment_is_public": true
+            })))
+            .respond_with(job_response("merge"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let request = TicketMergeRequest::new(vec![6, 7])
+            .source_comment("Closing in favour of #5", false)
+            .target_comment("Merged #6 and #7", true);
+        let job = client.merge_tickets(5, request).await.unwrap();
+        assert_eq!(job.id.as_deref(), Some("merge"));
+
+        let into_itself = client
+            .merge_tickets(5, TicketMergeRequest::new(vec![5]))
+            .await;
+        assert!(matches!(into_itself, Err(ZendeskError::Validation { .. })));
+        let no_sources = client
+            .merge_tickets(5, TicketMergeRequest::new(vec![]))
+            .await;
+        assert!(matches!(no_sources, Err(ZendeskError::Validation { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_link_incident_to_problem() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/tickets/10.json"))
+            .respond_with(ticket_response(10, "problem"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/tickets/11.json"))
+            .respond_with(ticket_response(11, "question"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/tickets/12.json"))
+            .respond_with(ticket_response(12, "task"))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v2/tickets/11.json"))
+            .and(body_json(
+                json!({ "ticket": { "type": "incident", "problem_id": 10 } }),
+            ))
+            .respond_with(ticket_response(11, "incident"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let incident = client.link_incident_to_problem(11, 10).await.unwrap();
+        assert_eq!(incident.ticket_type, Some(TicketType::Incident));
+
+        // Ticket 12 is not a problem, and a problem cannot become an incident
+        let not_a_problem = client.link_incident_to_problem(11, 12).await;
+        assert!(matches!(
+            not_a_problem,
+            Err(ZendeskError::Validation { .. })
+        ));
+        let problem_as_incident = client.link_incident_to_problem(10, 10).await;
+        assert!(matches!(
+            problem_as_incident,
+            Err(ZendeskError::Validation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_link_incidents_rejects_problems_before_updating() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/tickets/10.json"))
+            .respond_with(ticket_response(10, "problem"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/tickets/show_many.json"))
+            .and(query_param("ids", "11,20"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "tickets": [
+                    { "id": 11, "subject": "A", "description": "A", "type": "question" },
+                    { "id": 20, "subject": "B", "description": "B", "type": "problem" }
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .respond_with(job_response("never"))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let error = client
+            .link_incidents_to_problem(&[11, 20], 10)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, ZendeskError::Validation { ref message } if message.contains("20"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ticket_participants() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/tickets/3/email_ccs.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "users": [{ "id": 8, "name": "Kim", "email": "kim@example.com" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v2/tickets/3.json"))
+            .and(body_json(json!({
+                "ticket": {
+                    "email_ccs": [
+                        { "user_email": "new@example.com", "action": "put" },
+                        { "user_id": 8, "action": "delete" }
+                    ]
+                }
+            })))
+            .respond_with(ticket_response(3, "question"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let ccs = client.list_ticket_email_ccs(3).await.unwrap();
+        assert_eq!(ccs[0].id, Some(8));
+
+        let changes = vec![
+            TicketParticipantChange::add_email("new@example.com"),
+            TicketParticipantChange::remove_user(8),
+        ];
+        client
+            .update_ticket_participants(3, changes, Vec::new())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_many_tickets_rejects_an_id() {
+        let client = mock_client("http://127.0.0.1:9");
+        let error = client
+            .update_many_tickets(&[1, 2], TicketBulkUpdate::for_ticket(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ZendeskError::Validation { .. }));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::trigger::{
+    Trigger, TriggerBatchUpdate, TriggerBatchUpdateRequest, TriggerCategoriesResponse,
+    TriggerCategory, TriggerCategoryBatchUpdate, TriggerCategoryJobItems,
+    TriggerCategoryJobRequest, TriggerCategoryJobResponse, TriggerCategoryJobResults,
+    TriggerCategoryRequest, TriggerCategoryResponse, TriggerCategoryUpsert, TriggerReorderRequest,
+    TriggerRequest, TriggerResponse, TriggerUpsert, TriggersResponse,
+};
+use crate::query::QueryParams;
+
+impl ZendeskClient {
+    pub async fn list_triggers(&self) -> Result<Vec<Trigger>> {
+        let response: TriggersResponse = self.get("triggers.json").await?;
+        Ok(response.triggers)
+    }
+
+    /// List triggers with query parameters (pagination, sorting, etc.)
+    pub async fn list_triggers_with_params(
+        &self,
+        params: &QueryParams,
+    ) -> Result<TriggersResponse> {
+        self.get_with_params("triggers.json", params).await
+    }
+
+    /// List every trigger, following `next_page`
+    pub async fn list_all_triggers(&self) -> Result<Vec<Trigger>> {
+        let mut response: TriggersResponse = self.get("triggers.json").await?;
+        let mut triggers = std::mem::take(&mut response.triggers);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            triggers.append(&mut response.triggers);
+        }
+
+        Ok(triggers)
+    }
+
+    pub async fn list_active_triggers(&self) -> Result<Vec<Trigger>> {
+        let response: TriggersResponse = self.get("triggers/active.json").await?;
+        Ok(response.triggers)
+    }
+
+    pub async fn get_trigger(&self, trigger_id: u64) -> Result<Trigger> {
+        let endpoint = format!("triggers/{}.json", trigger_id);
+        let response: TriggerResponse = self.get(&endpoint).await?;
+        Ok(response.trigger)
+    }
+
+    pub async fn create_trigger(&self, trigger: TriggerUpsert) -> Result<Trigger> {
+        let request = TriggerRequest { trigger };
+        let response: TriggerResponse = self.post("triggers.json", &request).await?;
+        Ok(response.trigger)
+    }
+
+    pub async fn update_trigger(&self, trigger_id: u64, trigger: TriggerUpsert) -> Result<Trigger> {
+        let endpoint = format!("triggers/{}.json", trigger_id);
+        let request = TriggerRequest { trigger };
+        let response: TriggerResponse = self.put(&endpoint, &request).await?;
+        Ok(response.trigger)
+    }
+
+    pub async fn delete_trigger(&self, trigger_id: u64) -> Result<()> {
+        let endpoint = format!("triggers/{}.json", trigger_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Update the position, active flag or category of many triggers at once
+    pub async fn update_many_triggers(
+        &self,
+        updates: Vec<TriggerBatchUpdate>,
+    ) -> Result<Vec<Trigger>> {
+        let request = TriggerBatchUpdateRequest { triggers: updates };
+        let response: TriggersResponse = self.put("triggers/update_many.json", &request).await?;
+        Ok(response.triggers)
+    }
+
+    /// Set the firing order of triggers; `trigger_ids` lists them first to last
+    pub async fn reorder_triggers(&self, trigger_ids: Vec<u64>) -> Result<Vec<Trigger>> {
+        let request = TriggerReorderRequest { trigger_ids };
+        let response: TriggersResponse = self.put("triggers/reorder.json", &request).await?;
+        Ok(response.triggers)
+    }
+
+    /// List every trigger category, following the cursor
+    pub async fn list_trigger_categories(&self) -> Result<Vec<TriggerCategory>> {
+        let mut response: TriggerCategoriesResponse =
+            self.get("trigger_categories.json?page[size]=100").await?;
+        let mut categories = std::mem::take(&mut response.trigger_categories);
+
+        while let Some(cursor) = response.next_cursor().map(str::to_string) {
+            let endpoint = format!(
+                "trigger_categories.json?page[size]=100&page[after]={}",
+                urlencoding::encode(&cursor)
+            );
+            response = self.get(&endpoint).await?;
+            categories.append(&mut response.trigger_categories);
+        }
+
+        Ok(categories)
+    }
+
+    pub async fn get_trigger_category(&self, category_id: &str) -> Result<TriggerCategory> {
+        let endpoint = format!("trigger_categories/{}.json", category_id);
+        let response: TriggerCategoryResponse = self.get(&endpoint).await?;
+        Ok(response.trigger_category)
+    }
+
+    pub async fn create_trigger_category(
+        &self,
+        category: TriggerCategoryUpsert,
+    ) -> Result<TriggerCategory> {
+        let request = TriggerCategoryRequest {
+            trigger_category: category,
+        };
+        let response: TriggerCategoryResponse =
+            self.post("trigger_categories.json", &request).await?;
+        Ok(response.trigger_category)
+    }
+
+    pub async fn update_trigger_category(
+        &self,
+        category_id: &str,
+        category: TriggerCategoryUpsert,
+    ) -> Result<TriggerCategory> {
+        let endpoint = format!("trigger_categories/{}.json", category_id);
+        let request = TriggerCategoryRequest {
+            trigger_category: category,
+        };
+        let response: TriggerCategoryResponse = self.patch(&endpoint, &request).await?;
+        Ok(response.trigger_category)
+    }
+
+    /// Delete a trigger category. Zendesk refuses while it still contains triggers.
+    pub async fn delete_trigger_category(&self, category_id: &str) -> Result<()> {
+        let endpoint = format!("trigger_categories/{}.json", category_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Reposition trigger categories and move triggers between them in one job
+    pub async fn update_many_trigger_categories(
+        &self,
+        items: TriggerCategoryJobItems,
+    ) -> Result<TriggerCategoryJobResults> {
+        let request = TriggerCategoryJobRequest::patch(items);
+        let response: TriggerCategoryJobResponse =
+            self.post("trigger_categories/jobs.json", &request).await?;
+        Ok(response.results)
+    }
+
+    /// Set the order of trigger categories; `category_ids` lists them first to last
+    pub async fn reorder_trigger_categories(
+        &self,
+        category_ids: Vec<String>,
+    ) -> Result<Vec<TriggerCategory>> {
+        let items = TriggerCategoryJobItems {
+            trigger_categories: category_ids
+                .into_iter()
+                .zip(1..)
+                .map(|(id, position)| TriggerCategoryBatchUpdate { id, position })
+                .collect(),
+            triggers: Vec::new(),
+        };
+        let results = self.update_many_trigger_categories(items).await?;
+        Ok(results.trigger_categories)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::client::ZendeskClient;
+use crate::errors::Result;
+use crate::models::user_identity::{
+    UserIdentitiesResponse, UserIdentity, UserIdentityCreate, UserIdentityCreateRequest,
+    UserIdentityResponse, UserIdentityUpdate, UserIdentityUpdateRequest,
+};
+
+impl ZendeskClient {
+    /// List every identity of a user, following `next_page`
+    pub async fn list_user_identities(&self, user_id: u64) -> Result<Vec<UserIdentity>> {
+        let endpoint = format!("users/{}/identities.json", user_id);
+        let mut response: UserIdentitiesResponse = self.get(&endpoint).await?;
+        let mut identities = std::mem::take(&mut response.identities);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            identities.append(&mut response.identities);
+        }
+
+        Ok(identities)
+    }
+
+    pub async fn get_user_identity(&self, user_id: u64, identity_id: u64) -> Result<UserIdentity> {
+        let endpoint = format!("users/{}/identities/{}.json", user_id, identity_id);
+        let response: UserIdentityResponse = self.get(&endpoint).await?;
+        Ok(response.identity)
+    }
+
+    /// Attach another identity, such as a secondary email address, to an existing user
+    pub async fn create_user_identity(
+        &self,
+        user_id: u64,
+        identity: UserIdentityCreate,
+    ) -> Result<UserIdentity> {
+        let endpoint = format!("users/{}/identities.json", user_id);
+        let request = UserIdentityCreateRequest { identity };
+        let response: UserIdentityResponse = self.post(&endpoint, &request).await?;
+        Ok(response.identity)
+    }
+
+    pub async fn update_user_identity(
+        &self,
+        user_id: u64,
+        identity_id: u64,
+        identity: UserIdentityUpdate,
+    ) -> Result<UserIdentity> {
+        let endpoint = format!("users/{}/identities/{}.json", user_id, identity_id);
+        let request = UserIdentityUpdateRequest { identity };
+        let response: UserIdentityResponse = self.put(&endpoint, &request).await?;
+        Ok(response.identity)
+    }
+
+    pub async fn delete_user_identity(&self, user_id: u64, identity_id: u64) -> Result<()> {
+        let endpoint = format!("users/{}/identities/{}.json", user_id, identity_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// Make an identity the user's primary one. Returns all of the user's identities.
+    pub async fn make_user_identity_primary(
+        &self,
+        user_id: u64,
+        identity_id: u64,
+    ) -> Result<Vec<UserIdentity>> {
+        let endpoint = format!(
+            "users/{}/identities/{}/make_primary.json",
+            user_id, identity_id
+        );
+        let response: UserIdentitiesResponse = self.put(&endpoint, &serde_json::json!({})).await?;
+        Ok(response.identities)
+    }
+
+    /// Mark an identity as verified without emailing the user
+    pub async fn verify_user_identity(
+        &self,
+        user_id: u64,
+        identity_id: u64,
+    ) -> Result<UserIdentity> {
+        let endpoint = format!("users/{}/identities/{}/verify.json", user_id, identity_id);
+        let response: UserIdentityResponse = self.put(&endpoint, &serde_json::json!({})).await?;
+        Ok(response.identity)
+    }
+
+    /// Send the user an email asking them to verify the identity
+    pub async fn request_user_identity_verification(
+        &self,
+        user_id: u64,
+        identity_id: u64,
+    ) -> Result<()> {
+        let endpoint = format!(
+            "users/{}/identities/{}/request_verification.json",
+            user_id, identity_id
+        );
+        let _: serde_json::Value = self.put(&endpoint, &serde_json::json!({})).await?;
+        Ok(())
+    }
+}
@@ -1,7 +1,12 @@
 use crate::client::ZendeskClient;
-use crate::errors::Result;
+use crate::endpoints::job_statuses::submit_in_chunks;
+use crate::endpoints::tickets::{BULK_CHUNK_SIZE, join_ids};
+use crate::errors::{Result, ZendeskError};
+use crate::models::job_status::{BulkJobResponse, JobState, JobStatus};
 use crate::models::user::{
-    User, UserCreateRequest, UserResponse, UsersResponse
+    DeletedUser, DeletedUserResponse, DeletedUsersResponse, PermanentDeletionResponse, User,
+    UserCreate, UserCreateManyRequest, UserCreateRequest, UserResponse, UserUpdate,
+    UserUpdateManyRequest, UserUpdateRequest, UsersResponse,
 };
 
 impl ZendeskClient {
@@ -9,46 +14,341 @@ impl ZendeskClient {
         let response: UserResponse = self.post("users.json", &user_request).await?;
         Ok(response.user)
     }
-    
+
     pub async fn get_user(&self, user_id: u64) -> Result<User> {
         let endpoint = format!("users/{}.json", user_id);
         let response: UserResponse = self.get(&endpoint).await?;
         Ok(response.user)
     }
-    
+
     pub async fn get_user_by_email(&self, email: &str) -> Result<User> {
         let endpoint = format!("users/search.json?query=email:{}", email);
         let response: UsersResponse = self.get(&endpoint).await?;
-        response.users.into_iter().next()
+        response
+            .users
+            .into_iter()
+            .next()
             .ok_or_else(|| crate::errors::ZendeskError::validation("User not found"))
     }
-    
+
     pub async fn update_user(&self, user_id: u64, user_request: UserCreateRequest) -> Result<User> {
         let endpoint = format!("users/{}.json", user_id);
         let response: UserResponse = self.put(&endpoint, &user_request).await?;
         Ok(response.user)
     }
-    
+
     pub async fn delete_user(&self, user_id: u64) -> Result<()> {
         let endpoint = format!("users/{}.json", user_id);
-        let _: serde_json::Value = self.delete(&endpoint).await?;
+        self.delete_no_content(&endpoint).await?;
         Ok(())
     }
-    
+
     pub async fn list_users(&self) -> Result<Vec<User>> {
         let response: UsersResponse = self.get("users.json").await?;
         Ok(response.users)
     }
-    
+
     pub async fn list_users_in_organization(&self, organization_id: u64) -> Result<Vec<User>> {
         let endpoint = format!("organizations/{}/users.json", organization_id);
         let response: UsersResponse = self.get(&endpoint).await?;
         Ok(response.users)
     }
-    
+
     pub async fn search_users(&self, query: &str) -> Result<Vec<User>> {
         let endpoint = format!("users/search.json?query={}", query);
         let response: UsersResponse = self.get(&endpoint).await?;
         Ok(response.users)
     }
-}
\ No newline at end of file
+
+    /// Search users with `query`, URL-encoded, following every result page
+    pub async fn search_all_users(&self, query: &str) -> Result<Vec<User>> {
+        let endpoint = format!("users/search.json?query={}", urlencoding::encode(query));
+        let mut response: UsersResponse = self.get(&endpoint).await?;
+        let mut users = std::mem::take(&mut response.users);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            users.append(&mut response.users);
+        }
+
+        Ok(users)
+    }
+
+    /// Find a user by the id your system assigned, if any
+    pub async fn get_user_by_external_id(&self, external_id: &str) -> Result<Option<User>> {
+        let endpoint = format!(
+            "users/search.json?external_id={}",
+            urlencoding::encode(external_id)
+        );
+        let response: UsersResponse = self.get(&endpoint).await?;
+        Ok(response.users.into_iter().next())
+    }
+
+    /// Create a user, or update the one matching its email or `external_id`
+    pub async fn create_or_update_user(&self, user_request: UserCreateRequest) -> Result<User> {
+        check_match_keys(std::slice::from_ref(&user_request.user))?;
+        let response: UserResponse = self
+            .post("users/create_or_update.json", &user_request)
+            .await?;
+        Ok(response.user)
+    }
+
+    /// Create or update many users via `users/create_or_update_many`, split into batches
+    /// of 100. Returns one job status per batch.
+    pub async fn create_or_update_many_users(
+        &self,
+        users: Vec<UserCreate>,
+    ) -> Result<Vec<JobStatus>> {
+        check_match_keys(&users)?;
+        self.post_users_in_chunks("users/create_or_update_many.json", users)
+            .await
+    }
+
+    /// Create many users via `users/create_many`, split into batches of 100
+    pub async fn create_many_users(&self, users: Vec<UserCreate>) -> Result<Vec<JobStatus>> {
+        self.post_users_in_chunks("users/create_many.json", users)
+            .await
+    }
+
+    async fn post_users_in_chunks(
+        &self,
+        endpoint: &str,
+        users: Vec<UserCreate>,
+    ) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(&users, BULK_CHUNK_SIZE, |chunk| async move {
+            let request = UserCreateManyRequest {
+                users: chunk.to_vec(),
+            };
+            let response: BulkJobResponse = self.post(endpoint, &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Apply the same change to many users by id via `users/update_many`
+    pub async fn update_many_users_by_ids(
+        &self,
+        user_ids: &[u64],
+        update: UserUpdate,
+    ) -> Result<Vec<JobStatus>> {
+        let update = &update;
+        submit_in_chunks(user_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!("users/update_many.json?ids={}", join_ids(chunk));
+            let request = UserUpdateRequest {
+                user: update.clone(),
+            };
+            let response: BulkJobResponse = self.put(&endpoint, &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Apply the same change to many users by external id via `users/update_many`
+    pub async fn update_many_users_by_external_ids(
+        &self,
+        external_ids: &[String],
+        update: UserUpdate,
+    ) -> Result<Vec<JobStatus>> {
+        let update = &update;
+        submit_in_chunks(external_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!(
+                "users/update_many.json?external_ids={}",
+                join_external_ids(chunk)
+            );
+            let request = UserUpdateRequest {
+                user: update.clone(),
+            };
+            let response: BulkJobResponse = self.put(&endpoint, &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Apply individual changes via `users/update_many`; each update names its user with
+    /// `id` or `external_id`. Nothing is sent if any update names neither.
+    pub async fn update_many_users(&self, updates: Vec<UserUpdate>) -> Result<Vec<JobStatus>> {
+        let unnamed = updates.iter().position(|update| {
+            update.id.is_none()
+                && update
+                    .external_id
+                    .as_deref()
+                    .is_none_or(|external_id| external_id.trim().is_empty())
+        });
+        if let Some(index) = unnamed {
+            return Err(ZendeskError::validation(format!(
+                "User update {} has neither an id nor an external_id",
+                index
+            )));
+        }
+
+        submit_in_chunks(&updates, BULK_CHUNK_SIZE, |chunk| async move {
+            let request = UserUpdateManyRequest {
+                users: chunk.to_vec(),
+            };
+            let response: BulkJobResponse = self.put("users/update_many.json", &request).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Delete many users by id via `users/destroy_many`
+    pub async fn destroy_many_users(&self, user_ids: &[u64]) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(user_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!("users/destroy_many.json?ids={}", join_ids(chunk));
+            let response: BulkJobResponse = self.delete(&endpoint).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Delete many users by external id via `users/destroy_many`
+    pub async fn destroy_many_users_by_external_ids(
+        &self,
+        external_ids: &[String],
+    ) -> Result<Vec<JobStatus>> {
+        submit_in_chunks(external_ids, BULK_CHUNK_SIZE, |chunk| async move {
+            let endpoint = format!(
+                "users/destroy_many.json?external_ids={}",
+                join_external_ids(chunk)
+            );
+            let response: BulkJobResponse = self.delete(&endpoint).await?;
+            Ok(response.job_status)
+        })
+        .await
+    }
+
+    /// Suspend a user so they can no longer sign in or submit tickets
+    pub async fn suspend_user(&self, user_id: u64) -> Result<User> {
+        self.set_user_suspended(user_id, true).await
+    }
+
+    pub async fn unsuspend_user(&self, user_id: u64) -> Result<User> {
+        self.set_user_suspended(user_id, false).await
+    }
+
+    async fn set_user_suspended(&self, user_id: u64, suspended: bool) -> Result<User> {
+        let endpoint = format!("users/{}.json", user_id);
+        let request = UserUpdateRequest {
+            user: UserUpdate::new().suspended(suspended),
+        };
+        let response: UserResponse = self.put(&endpoint, &request).await?;
+        Ok(response.user)
+    }
+
+    /// List every soft-deleted user, following `next_page`
+    pub async fn list_deleted_users(&self) -> Result<Vec<DeletedUser>> {
+        let mut response: DeletedUsersResponse = self.get("deleted_users.json").await?;
+        let mut deleted_users = std::mem::take(&mut response.deleted_users);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            deleted_users.append(&mut response.deleted_users);
+        }
+
+        Ok(deleted_users)
+    }
+
+    pub async fn get_deleted_user(&self, user_id: u64) -> Result<DeletedUser> {
+        let endpoint = format!("deleted_users/{}.json", user_id);
+        let response: DeletedUserResponse = self.get(&endpoint).await?;
+        Ok(response.deleted_user)
+    }
+
+    /// Permanently delete a user that was already soft-deleted with `delete_user`. Returns
+    /// the deletion job if Zendesk runs the purge in the background.
+    pub async fn permanently_delete_deleted_user(&self, user_id: u64) -> Result<Option<JobStatus>> {
+        let endpoint = format!("deleted_users/{}.json", user_id);
+        let response: PermanentDeletionResponse = self.delete(&endpoint).await?;
+        Ok(response.job_status)
+    }
+
+    /// Erase a user for a data-deletion (GDPR) request: soft-delete the user if still
+    /// active, permanently delete them, and wait for any deletion job to finish.
+    /// This cannot be undone.
+    pub async fn permanently_delete_user(&self, user_id: u64) -> Result<Option<JobStatus>> {
+        let user = self.get_user(user_id).await?;
+        if user.active != Some(false) {
+            self.delete_user(user_id).await?;
+        }
+
+        let Some(job) = self.permanently_delete_deleted_user(user_id).await? else {
+            return Ok(None);
+        };
+        let finished = self.job_handle(job)?.wait().await?;
+        if finished.state() != Some(JobState::Completed) {
+            return Err(ZendeskError::UnexpectedResponse(format!(
+                "permanent deletion of user {} ended as {}: {}",
+                user_id,
+                finished.status.as_deref().unwrap_or("unknown"),
+                finished.message.as_deref().unwrap_or_default()
+            )));
+        }
+        Ok(Some(finished))
+    }
+}
+
+fn join_external_ids(external_ids: &[String]) -> String {
+    external_ids
+        .iter()
+        .map(|id| urlencoding::encode(id).into_owned())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// `create_or_update` matches on email or external id, so every user needs one of them
+fn check_match_keys(users: &[UserCreate]) -> Result<()> {
+    let is_set = |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+    match users
+        .iter()
+        .position(|user| !is_set(&user.email) && !is_set(&user.external_id))
+    {
+        Some(index) => Err(ZendeskError::validation(format!(
+            "User {} has neither an email nor an external_id to match on",
+            index
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthMethod;
+    use crate::config::ZendeskConfig;
+
+    #[tokio::test]
+    async fn test_update_many_users_requires_id_or_external_id() {
+        let config = ZendeskConfig::new("example", AuthMethod::api_token("a@example.com", "t"));
+        let client = ZendeskClient::new(config).unwrap();
+        let updates: Vec<UserUpdate> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Ann" },
+            { "external_id": " ", "name": "Bob" }
+        ]))
+        .unwrap();
+
+        let error = client.update_many_users(updates).await.unwrap_err();
+        assert!(matches!(error, ZendeskError::Validation { .. }));
+        assert!(error.to_string().contains("User update 1"));
+    }
+
+    #[tokio::test]
+    async fn test_create_or_update_requires_email_or_external_id() {
+        let config = ZendeskConfig::new("example", AuthMethod::api_token("a@example.com", "t"));
+        let client = ZendeskClient::new(config).unwrap();
+
+        let by_external_id = User::builder_with_external_id("Ann", "crm-1").build_user();
+        assert!(check_match_keys(std::slice::from_ref(&by_external_id)).is_ok());
+        let body = serde_json::to_value(&by_external_id).unwrap();
+        assert!(body.get("email").is_none());
+        assert_eq!(body["external_id"], "crm-1");
+
+        let mut unmatched = by_external_id.clone();
+        unmatched.external_id = None;
+        let error = client
+            .create_or_update_many_users(vec![by_external_id, unmatched])
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ZendeskError::Validation { .. }));
+        assert!(error.to_string().contains("User 1"));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::client::ZendeskClient;
+use crate::endpoints::tickets::join_ids;
+use crate::errors::Result;
+use crate::models::ticket::{Ticket, TicketsResponse};
+use crate::models::view::{
+    View, ViewCount, ViewCountsResponse, ViewExecuteResponse, ViewPreview, ViewPreviewRequest,
+    ViewRequest, ViewResponse, ViewUpsert, ViewsResponse,
+};
+use crate::query::QueryParams;
+
+/// Most view ids `views/count_many` accepts per request
+const COUNT_MANY_LIMIT: usize = 20;
+
+impl ZendeskClient {
+    pub async fn list_views(&self) -> Result<Vec<View>> {
+        let response: ViewsResponse = self.get("views.json").await?;
+        Ok(response.views)
+    }
+
+    /// List views with query parameters (pagination, sorting, etc.)
+    pub async fn list_views_with_params(&self, params: &QueryParams) -> Result<ViewsResponse> {
+        self.get_with_params("views.json", params).await
+    }
+
+    /// List every view, following `next_page`
+    pub async fn list_all_views(&self) -> Result<Vec<View>> {
+        let mut response: ViewsResponse = self.get("views.json").await?;
+        let mut views = std::mem::take(&mut response.views);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            views.append(&mut response.views);
+        }
+
+        Ok(views)
+    }
+
+    /// List the active views available to the current user
+    pub async fn list_active_views(&self) -> Result<Vec<View>> {
+        let response: ViewsResponse = self.get("views/active.json").await?;
+        Ok(response.views)
+    }
+
+    /// List active views with query parameters (pagination, sorting, etc.)
+    pub async fn list_active_views_with_params(
+        &self,
+        params: &QueryParams,
+    ) -> Result<ViewsResponse> {
+        self.get_with_params("views/active.json", params).await
+    }
+
+    pub async fn get_view(&self, view_id: u64) -> Result<View> {
+        let endpoint = format!("views/{}.json", view_id);
+        let response: ViewResponse = self.get(&endpoint).await?;
+        Ok(response.view)
+    }
+
+    pub async fn create_view(&self, view: ViewUpsert) -> Result<View> {
+        let request = ViewRequest { view };
+        let response: ViewResponse = self.post("views.json", &request).await?;
+        Ok(response.view)
+    }
+
+    pub async fn update_view(&self, view_id: u64, view: ViewUpsert) -> Result<View> {
+        let endpoint = format!("views/{}.json", view_id);
+        let request = ViewRequest { view };
+        let response: ViewResponse = self.put(&endpoint, &request).await?;
+        Ok(response.view)
+    }
+
+    pub async fn delete_view(&self, view_id: u64) -> Result<()> {
+        let endpoint = format!("views/{}.json", view_id);
+        self.delete_no_content(&endpoint).await?;
+        Ok(())
+    }
+
+    /// List one page of the tickets in a view
+    pub async fn list_view_tickets(
+        &self,
+        view_id: u64,
+        params: &QueryParams,
+    ) -> Result<TicketsResponse> {
+        let endpoint = format!("views/{}/tickets.json", view_id);
+        self.get_with_params(&endpoint, params).await
+    }
+
+    /// List every ticket in a view, following `next_page`
+    pub async fn list_all_view_tickets(&self, view_id: u64) -> Result<Vec<Ticket>> {
+        let mut response = self.list_view_tickets(view_id, &QueryParams::new()).await?;
+        let mut tickets = std::mem::take(&mut response.tickets);
+
+        while let Some(next_page) = response.next_page.take() {
+            response = self.get(&next_page).await?;
+            tickets.append(&mut response.tickets);
+        }
+
+        Ok(tickets)
+    }
+
+    /// Execute a view, returning its columns and one row per ticket
+    pub async fn execute_view(
+        &self,
+        view_id: u64,
+        params: &QueryParams,
+    ) -> Result<ViewExecuteResponse> {
+        let endpoint = format!("views/{}/execute.json", view_id);
+        self.get_with_params(&endpoint, params).await
+    }
+
+    /// Ticket counts for views, requested 20 at a time as `views/count_many` allows.
+    /// Counts that are not `fresh` are still being computed.
+    pub async fn count_views(&self, view_ids: &[u64]) -> Result<Vec<ViewCount>> {
+        let mut counts = Vec::with_capacity(view_ids.len());
+        for chunk in view_ids.chunks(COUNT_MANY_LIMIT) {
+            let endpoint = format!("views/count_many.json?ids={}", join_ids(chunk));
+            let response: ViewCountsResponse = self.get(&endpoint).await?;
+            counts.extend(response.view_counts);
+        }
+        Ok(counts)
+    }
+
+    /// Run ad-hoc conditions as a view without saving it
+    pub async fn preview_view(
+        &self,
+        preview: ViewPreview,
+        params: &QueryParams,
+    ) -> Result<ViewExecuteResponse> {
+        let request = ViewPreviewRequest { view: preview };
+        self.post_with_params("views/preview.json", &request, params)
+            .await
+    }
+}
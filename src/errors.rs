@@ -1,5 +1,9 @@
 use thiserror::Error;
 
+use crate::models::job_status::JobStatus;
+use crate::models::redaction::PendingRedaction;
+use crate::sync::apply::SyncReport;
+
 pub type Result<T> = std::result::Result<T, ZendeskError>;
 
 #[derive(Error, Debug)]
@@ -10,6 +14,9 @@ pub enum ZendeskError {
     #[error("JSON serialization/deserialization failed: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("URL parsing failed: {0}")]
     Url(#[from] url::ParseError),
 
@@ -33,6 +40,35 @@ pub enum ZendeskError {
 
     #[error("Unexpected response format: {0}")]
     UnexpectedResponse(String),
+
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    /// A chunked bulk request failed part-way; `submitted` holds the jobs of the chunks
+    /// that were accepted before the failure and are still running
+    #[error("Bulk request failed after {} job(s) were submitted: {source}", .submitted.len())]
+    Bulk {
+        submitted: Vec<JobStatus>,
+        source: Box<ZendeskError>,
+    },
+
+    /// Redacting several strings failed part-way; `applied` holds the redactions that
+    /// were already made and cannot be undone
+    #[error("Redaction failed after {} string(s) were redacted: {source}", .applied.len())]
+    Redaction {
+        applied: Vec<PendingRedaction>,
+        source: Box<ZendeskError>,
+    },
+
+    /// Applying a sync plan failed part-way; `report` counts the changes already applied
+    #[error(
+        "Sync stopped after {} created, {} updated and {} deleted: {source}",
+        .report.created, .report.updated, .report.deleted
+    )]
+    Sync {
+        report: SyncReport,
+        source: Box<ZendeskError>,
+    },
 }
 
 impl ZendeskError {
@@ -60,4 +96,28 @@ impl ZendeskError {
             message: message.into(),
         }
     }
+
+    /// Jobs already submitted by a bulk request that failed part-way
+    pub fn submitted_jobs(&self) -> &[JobStatus] {
+        match self {
+            Self::Bulk { submitted, .. } => submitted,
+            _ => &[],
+        }
+    }
+
+    /// Redactions already applied by a multi-string redaction that failed part-way
+    pub fn applied_redactions(&self) -> &[PendingRedaction] {
+        match self {
+            Self::Redaction { applied, .. } => applied,
+            _ => &[],
+        }
+    }
+
+    /// Changes already made by a sync plan that failed part-way
+    pub fn sync_report(&self) -> Option<SyncReport> {
+        match self {
+            Self::Sync { report, .. } => Some(*report),
+            _ => None,
+        }
+    }
 }
@@ -5,6 +5,7 @@ pub mod endpoints;
 pub mod errors;
 pub mod models;
 pub mod query;
+pub mod sync;
 
 pub use client::ZendeskClient;
 pub use config::ZendeskConfig;
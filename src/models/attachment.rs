@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ticket::CommentAttachment;
+
+/// Result of uploading a file to `uploads`. Pass `token` in a comment's `uploads` to
+/// attach every file uploaded under it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Upload {
+    pub token: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<CommentAttachment>,
+
+    #[serde(default)]
+    pub attachments: Vec<CommentAttachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadResponse {
+    pub upload: Upload,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentResponse {
+    pub attachment: CommentAttachment,
+}
+
+/// Guess a MIME type from a file name's extension, falling back to
+/// `application/octet-stream`
+pub fn content_type_for_filename(filename: &str) -> &'static str {
+    let extension = filename
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "txt" | "log" => "text/plain",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        "eml" => "message/rfc822",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_detection() {
+        assert_eq!(content_type_for_filename("screenshot.PNG"), "image/png");
+        assert_eq!(
+            content_type_for_filename("report.final.pdf"),
+            "application/pdf"
+        );
+        assert_eq!(content_type_for_filename("notes.txt"), "text/plain");
+        assert_eq!(
+            content_type_for_filename("archive"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_upload_deserialization() {
+        let response: UploadResponse = serde_json::from_value(serde_json::json!({
+            "upload": {
+                "token": "6bk3gql82em5nmf",
+                "expires_at": "2026-01-01T00:00:00Z",
+                "attachment": {
+                    "id": 498483,
+                    "file_name": "crash.log",
+                    "content_url": "https://company.zendesk.com/attachments/crash.log",
+                    "content_type": "text/plain",
+                    "size": 2532
+                },
+                "attachments": []
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(response.upload.token, "6bk3gql82em5nmf");
+        assert_eq!(response.upload.attachment.unwrap().id, 498483);
+    }
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::business_rule::{RuleAction, RuleConditions};
+
+/// A time-based business rule, evaluated hourly against every non-closed ticket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Automation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    #[serde(default)]
+    pub conditions: RuleConditions,
+
+    #[serde(default)]
+    pub actions: Vec<RuleAction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Automation {
+    /// The writable parts of this automation, e.g. to copy it or redeploy it elsewhere
+    pub fn to_upsert(&self) -> AutomationUpsert {
+        AutomationUpsert {
+            title: Some(self.title.clone()),
+            active: self.active,
+            position: self.position,
+            conditions: Some(self.conditions.clone()),
+            actions: Some(self.actions.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationResponse {
+    pub automation: Automation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationsResponse {
+    pub automations: Vec<Automation>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationRequest {
+    pub automation: AutomationUpsert,
+}
+
+/// Body for creating or updating an automation; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutomationUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RuleConditions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<RuleAction>>,
+}
+
+impl AutomationUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn conditions(mut self, conditions: RuleConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    pub fn action(mut self, action: RuleAction) -> Self {
+        self.actions.get_or_insert_with(Vec::new).push(action);
+        self
+    }
+}
+
+/// One entry of `PUT automations/update_many`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutomationBatchUpdate {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationBatchUpdateRequest {
+    pub automations: Vec<AutomationBatchUpdate>,
+}
@@ -0,0 +1,524 @@
+use serde::{Deserialize, Serialize};
+
+/// Comparison used by a trigger, automation or SLA condition
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum RuleOperator {
+    Is,
+    IsNot,
+    LessThan,
+    GreaterThan,
+    Changed,
+    NotChanged,
+    Value,
+    NotValue,
+    ValuePrevious,
+    NotValuePrevious,
+    Includes,
+    NotIncludes,
+    Other(String),
+}
+
+impl RuleOperator {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RuleOperator::Is => "is",
+            RuleOperator::IsNot => "is_not",
+            RuleOperator::LessThan => "less_than",
+            RuleOperator::GreaterThan => "greater_than",
+            RuleOperator::Changed => "changed",
+            RuleOperator::NotChanged => "not_changed",
+            RuleOperator::Value => "value",
+            RuleOperator::NotValue => "not_value",
+            RuleOperator::ValuePrevious => "value_previous",
+            RuleOperator::NotValuePrevious => "not_value_previous",
+            RuleOperator::Includes => "includes",
+            RuleOperator::NotIncludes => "not_includes",
+            RuleOperator::Other(other) => other,
+        }
+    }
+}
+
+impl From<String> for RuleOperator {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "is" => RuleOperator::Is,
+            "is_not" => RuleOperator::IsNot,
+            "less_than" => RuleOperator::LessThan,
+            "greater_than" => RuleOperator::GreaterThan,
+            "changed" => RuleOperator::Changed,
+            "not_changed" => RuleOperator::NotChanged,
+            "value" => RuleOperator::Value,
+            "not_value" => RuleOperator::NotValue,
+            "value_previous" => RuleOperator::ValuePrevious,
+            "not_value_previous" => RuleOperator::NotValuePrevious,
+            "includes" => RuleOperator::Includes,
+            "not_includes" => RuleOperator::NotIncludes,
+            _ => RuleOperator::Other(value),
+        }
+    }
+}
+
+impl From<RuleOperator> for String {
+    fn from(value: RuleOperator) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+/// A condition exactly as Zendesk sends it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RawCondition {
+    pub field: String,
+
+    pub operator: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+}
+
+/// A single rule condition. Conditions that don't fit a typed variant exactly are kept
+/// as `Raw`, so every condition serializes back to the JSON it was read from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawCondition", into = "RawCondition")]
+pub enum RuleCondition {
+    Status {
+        operator: RuleOperator,
+        value: String,
+    },
+    Priority {
+        operator: RuleOperator,
+        value: String,
+    },
+    TicketType {
+        operator: RuleOperator,
+        value: String,
+    },
+    GroupId {
+        operator: RuleOperator,
+        value: String,
+    },
+    AssigneeId {
+        operator: RuleOperator,
+        value: String,
+    },
+    /// `current_tags`, a space separated tag list
+    Tags {
+        operator: RuleOperator,
+        tags: Vec<String>,
+    },
+    /// `update_type`, either "Create" or "Change"
+    UpdateType {
+        operator: RuleOperator,
+        value: String,
+    },
+    /// Time based automation conditions such as `hours_since_solved`
+    HoursSince {
+        event: String,
+        operator: RuleOperator,
+        hours: String,
+    },
+    CustomField {
+        field_id: u64,
+        operator: RuleOperator,
+        value: serde_json::Value,
+    },
+    Raw(RawCondition),
+}
+
+impl RuleCondition {
+    pub fn new(
+        field: impl Into<String>,
+        operator: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        RawCondition {
+            field: field.into(),
+            operator: operator.into(),
+            value: Some(value.into()),
+        }
+        .into()
+    }
+
+    pub fn field(&self) -> String {
+        self.to_raw().field
+    }
+
+    pub fn to_raw(&self) -> RawCondition {
+        let (field, operator, value) = match self {
+            RuleCondition::Status { operator, value } => ("status".to_string(), operator, value),
+            RuleCondition::Priority { operator, value } => {
+                ("priority".to_string(), operator, value)
+            }
+            RuleCondition::TicketType { operator, value } => ("type".to_string(), operator, value),
+            RuleCondition::GroupId { operator, value } => ("group_id".to_string(), operator, value),
+            RuleCondition::AssigneeId { operator, value } => {
+                ("assignee_id".to_string(), operator, value)
+            }
+            RuleCondition::UpdateType { operator, value } => {
+                ("update_type".to_string(), operator, value)
+            }
+            RuleCondition::HoursSince {
+                event,
+                operator,
+                hours,
+            } => (format!("hours_since_{}", event), operator, hours),
+            RuleCondition::Tags { operator, tags } => {
+                return RawCondition {
+                    field: "current_tags".to_string(),
+                    operator: operator.as_str().to_string(),
+                    value: Some(tags.join(" ").into()),
+                };
+            }
+            RuleCondition::CustomField {
+                field_id,
+                operator,
+                value,
+            } => {
+                return RawCondition {
+                    field: format!("custom_fields_{}", field_id),
+                    operator: operator.as_str().to_string(),
+                    value: Some(value.clone()),
+                };
+            }
+            RuleCondition::Raw(raw) => return raw.clone(),
+        };
+        RawCondition {
+            field,
+            operator: operator.as_str().to_string(),
+            value: Some(value.clone().into()),
+        }
+    }
+
+    fn parse(raw: &RawCondition) -> Option<Self> {
+        let operator = RuleOperator::from(raw.operator.clone());
+        let text = raw.value.as_ref().and_then(serde_json::Value::as_str);
+
+        if let Some(field_id) = custom_field_id(&raw.field) {
+            return Some(RuleCondition::CustomField {
+                field_id,
+                operator,
+                value: raw.value.clone()?,
+            });
+        }
+        if let Some(event) = raw.field.strip_prefix("hours_since_") {
+            return Some(RuleCondition::HoursSince {
+                event: event.to_string(),
+                operator,
+                hours: text?.to_string(),
+            });
+        }
+
+        let value = text?.to_string();
+        let condition = match raw.field.as_str() {
+            "status" => RuleCondition::Status { operator, value },
+            "priority" => RuleCondition::Priority { operator, value },
+            "type" => RuleCondition::TicketType { operator, value },
+            "group_id" => RuleCondition::GroupId { operator, value },
+            "assignee_id" => RuleCondition::AssigneeId { operator, value },
+            "update_type" => RuleCondition::UpdateType { operator, value },
+            "current_tags" => RuleCondition::Tags {
+                operator,
+                tags: value.split_whitespace().map(str::to_string).collect(),
+            },
+            _ => return None,
+        };
+        Some(condition)
+    }
+}
+
+impl From<RawCondition> for RuleCondition {
+    fn from(raw: RawCondition) -> Self {
+        match RuleCondition::parse(&raw) {
+            Some(condition) if condition.to_raw() == raw => condition,
+            _ => RuleCondition::Raw(raw),
+        }
+    }
+}
+
+impl From<RuleCondition> for RawCondition {
+    fn from(condition: RuleCondition) -> Self {
+        match condition {
+            RuleCondition::Raw(raw) => raw,
+            condition => condition.to_raw(),
+        }
+    }
+}
+
+/// Conditions of a business rule: every `all` condition and at least one `any` condition
+/// must hold
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleConditions {
+    #[serde(default)]
+    pub all: Vec<RuleCondition>,
+
+    #[serde(default)]
+    pub any: Vec<RuleCondition>,
+}
+
+impl RuleConditions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn all(mut self, condition: RuleCondition) -> Self {
+        self.all.push(condition);
+        self
+    }
+
+    pub fn any(mut self, condition: RuleCondition) -> Self {
+        self.any.push(condition);
+        self
+    }
+}
+
+/// An action exactly as Zendesk sends it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RawAction {
+    pub field: String,
+
+    pub value: serde_json::Value,
+}
+
+/// A single rule action. Actions that don't fit a typed variant exactly are kept as `Raw`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawAction", into = "RawAction")]
+pub enum RuleAction {
+    Status(String),
+    Priority(String),
+    TicketType(String),
+    GroupId(String),
+    AssigneeId(String),
+    /// `current_tags`, adds tags
+    AddTags(Vec<String>),
+    RemoveTags(Vec<String>),
+    SetTags(Vec<String>),
+    NotifyUser {
+        recipient: String,
+        subject: String,
+        body: String,
+    },
+    NotifyGroup {
+        recipient: String,
+        subject: String,
+        body: String,
+    },
+    NotifyWebhook {
+        webhook_id: String,
+        body: String,
+    },
+    CustomField {
+        field_id: u64,
+        value: serde_json::Value,
+    },
+    Raw(RawAction),
+}
+
+impl RuleAction {
+    pub fn new(field: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        RawAction {
+            field: field.into(),
+            value: value.into(),
+        }
+        .into()
+    }
+
+    pub fn field(&self) -> String {
+        self.to_raw().field
+    }
+
+    pub fn to_raw(&self) -> RawAction {
+        let (field, value) = match self {
+            RuleAction::Status(value) => ("status".to_string(), value.clone().into()),
+            RuleAction::Priority(value) => ("priority".to_string(), value.clone().into()),
+            RuleAction::TicketType(value) => ("type".to_string(), value.clone().into()),
+            RuleAction::GroupId(value) => ("group_id".to_string(), value.clone().into()),
+            RuleAction::AssigneeId(value) => ("assignee_id".to_string(), value.clone().into()),
+            RuleAction::AddTags(tags) => ("current_tags".to_string(), tags.join(" ").into()),
+            RuleAction::RemoveTags(tags) => ("remove_tags".to_string(), tags.join(" ").into()),
+            RuleAction::SetTags(tags) => ("set_tags".to_string(), tags.join(" ").into()),
+            RuleAction::NotifyUser {
+                recipient,
+                subject,
+                body,
+            } => (
+                "notification_user".to_string(),
+                serde_json::json!([recipient, subject, body]),
+            ),
+            RuleAction::NotifyGroup {
+                recipient,
+                subject,
+                body,
+            } => (
+                "notification_group".to_string(),
+                serde_json::json!([recipient, subject, body]),
+            ),
+            RuleAction::NotifyWebhook { webhook_id, body } => (
+                "notification_webhook".to_string(),
+                serde_json::json!([webhook_id, body]),
+            ),
+            RuleAction::CustomField { field_id, value } => {
+                (format!("custom_fields_{}", field_id), value.clone())
+            }
+            RuleAction::Raw(raw) => return raw.clone(),
+        };
+        RawAction { field, value }
+    }
+
+    fn parse(raw: &RawAction) -> Option<Self> {
+        if let Some(field_id) = custom_field_id(&raw.field) {
+            return Some(RuleAction::CustomField {
+                field_id,
+                value: raw.value.clone(),
+            });
+        }
+
+        let strings: Option<Vec<String>> = raw.value.as_array().and_then(|items| {
+            items
+                .iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect()
+        });
+        match (raw.field.as_str(), strings.as_deref()) {
+            ("notification_user", Some([recipient, subject, body])) => {
+                return Some(RuleAction::NotifyUser {
+                    recipient: recipient.clone(),
+                    subject: subject.clone(),
+                    body: body.clone(),
+                });
+            }
+            ("notification_group", Some([recipient, subject, body])) => {
+                return Some(RuleAction::NotifyGroup {
+                    recipient: recipient.clone(),
+                    subject: subject.clone(),
+                    body: body.clone(),
+                });
+            }
+            ("notification_webhook", Some([webhook_id, body])) => {
+                return Some(RuleAction::NotifyWebhook {
+                    webhook_id: webhook_id.clone(),
+                    body: body.clone(),
+                });
+            }
+            _ => {}
+        }
+
+        let value = raw.value.as_str()?.to_string();
+        let tags = || value.split_whitespace().map(str::to_string).collect();
+        let action = match raw.field.as_str() {
+            "status" => RuleAction::Status(value),
+            "priority" => RuleAction::Priority(value),
+            "type" => RuleAction::TicketType(value),
+            "group_id" => RuleAction::GroupId(value),
+            "assignee_id" => RuleAction::AssigneeId(value),
+            "current_tags" => RuleAction::AddTags(tags()),
+            "remove_tags" => RuleAction::RemoveTags(tags()),
+            "set_tags" => RuleAction::SetTags(tags()),
+            _ => return None,
+        };
+        Some(action)
+    }
+}
+
+impl From<RawAction> for RuleAction {
+    fn from(raw: RawAction) -> Self {
+        match RuleAction::parse(&raw) {
+            Some(action) if action.to_raw() == raw => action,
+            _ => RuleAction::Raw(raw),
+        }
+    }
+}
+
+impl From<RuleAction> for RawAction {
+    fn from(action: RuleAction) -> Self {
+        match action {
+            RuleAction::Raw(raw) => raw,
+            action => action.to_raw(),
+        }
+    }
+}
+
+/// The id in a `custom_fields_<id>` field name, only if it round-trips exactly
+fn custom_field_id(field: &str) -> Option<u64> {
+    let id = field.strip_prefix("custom_fields_")?;
+    id.parse()
+        .ok()
+        .filter(|parsed: &u64| parsed.to_string() == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_conditions_round_trip() {
+        let source = json!({
+            "all": [
+                { "field": "status", "operator": "less_than", "value": "solved" },
+                { "field": "current_tags", "operator": "includes", "value": "vip  urgent" },
+                { "field": "custom_fields_360001", "operator": "is", "value": "enterprise" },
+                { "field": "hours_since_solved", "operator": "greater_than", "value": "48" },
+                { "field": "assignee_id", "operator": "changed" }
+            ],
+            "any": [
+                { "field": "group_id", "operator": "is", "value": 42 },
+                { "field": "via_id", "operator": "is", "value": "4" }
+            ]
+        });
+
+        let conditions: RuleConditions = serde_json::from_value(source.clone()).unwrap();
+        assert_eq!(
+            conditions.all[0],
+            RuleCondition::Status {
+                operator: RuleOperator::LessThan,
+                value: "solved".to_string()
+            }
+        );
+        // Double spaces would not survive the tag split, so the raw form is kept
+        assert!(matches!(conditions.all[1], RuleCondition::Raw(_)));
+        assert!(matches!(
+            conditions.all[2],
+            RuleCondition::CustomField {
+                field_id: 360001,
+                ..
+            }
+        ));
+        assert!(matches!(
+            conditions.all[3],
+            RuleCondition::HoursSince { .. }
+        ));
+        assert!(matches!(conditions.all[4], RuleCondition::Raw(_)));
+        assert!(matches!(conditions.any[0], RuleCondition::Raw(_)));
+        assert_eq!(serde_json::to_value(&conditions).unwrap(), source);
+    }
+
+    #[test]
+    fn test_actions_round_trip() {
+        let source = json!([
+            { "field": "status", "value": "open" },
+            { "field": "current_tags", "value": "escalated billing" },
+            { "field": "notification_user", "value": ["requester_id", "Re: {{ticket.title}}", "Thanks"] },
+            { "field": "notification_webhook", "value": ["01GZ", "{\"id\": \"{{ticket.id}}\"}"] },
+            { "field": "custom_fields_360002", "value": ["a", "b"] },
+            { "field": "satisfaction_score", "value": "offered_to_requester" }
+        ]);
+
+        let actions: Vec<RuleAction> = serde_json::from_value(source.clone()).unwrap();
+        assert_eq!(actions[0], RuleAction::Status("open".to_string()));
+        assert_eq!(
+            actions[1],
+            RuleAction::AddTags(vec!["escalated".to_string(), "billing".to_string()])
+        );
+        assert!(matches!(actions[2], RuleAction::NotifyUser { .. }));
+        assert!(matches!(actions[3], RuleAction::NotifyWebhook { .. }));
+        assert!(matches!(
+            actions[4],
+            RuleAction::CustomField {
+                field_id: 360002,
+                ..
+            }
+        ));
+        assert!(matches!(actions[5], RuleAction::Raw(_)));
+        assert_eq!(serde_json::to_value(&actions).unwrap(), source);
+    }
+}
@@ -0,0 +1,594 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::errors::{Result, ZendeskError};
+use crate::models::custom_object::CustomFieldOption;
+use crate::models::organization::Organization;
+use crate::models::ticket::{CustomField, Ticket};
+use crate::models::ticket_field::TicketField;
+use crate::models::user::User;
+
+/// How values of a custom field are interpreted, independent of whether the field belongs
+/// to tickets, users or organizations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldKind {
+    Text,
+    Dropdown,
+    Multiselect,
+    Date,
+    Checkbox,
+    Integer,
+    Decimal,
+    Lookup,
+    Other,
+}
+
+impl FieldKind {
+    /// Map a field `type` as returned by the ticket, user or organization fields API
+    pub fn from_type_name(type_name: &str) -> Self {
+        match type_name {
+            "text" | "textarea" | "regexp" | "partialcreditcard" => FieldKind::Text,
+            "tagger" | "dropdown" => FieldKind::Dropdown,
+            "multiselect" => FieldKind::Multiselect,
+            "date" => FieldKind::Date,
+            "checkbox" => FieldKind::Checkbox,
+            "integer" => FieldKind::Integer,
+            "decimal" => FieldKind::Decimal,
+            "lookup" => FieldKind::Lookup,
+            _ => FieldKind::Other,
+        }
+    }
+}
+
+/// A custom field value interpreted according to its field definition
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomFieldValue {
+    Empty,
+    Text(String),
+    Dropdown(CustomFieldOption),
+    Multiselect(Vec<CustomFieldOption>),
+    Date(NaiveDate),
+    Checkbox(bool),
+    Integer(i64),
+    Decimal(f64),
+    /// Id of the related record; numeric ids are returned as text too
+    Lookup(String),
+    /// System fields and field types this crate does not interpret
+    Other(serde_json::Value),
+}
+
+fn value_as_string(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn find_option<'a>(options: &'a [CustomFieldOption], value: &str) -> Option<&'a CustomFieldOption> {
+    options.iter().find(|option| option.value == value)
+}
+
+fn parse_value(
+    kind: FieldKind,
+    title: &str,
+    options: &[CustomFieldOption],
+    value: &serde_json::Value,
+) -> std::result::Result<CustomFieldValue, String> {
+    if value.is_null() {
+        return Ok(CustomFieldValue::Empty);
+    }
+
+    let invalid = || format!("Value {} is not valid for field '{}'", value, title);
+    let unknown_option = |tag: &str| format!("'{}' is not an option of field '{}'", tag, title);
+
+    let parsed = match kind {
+        FieldKind::Text => CustomFieldValue::Text(value_as_string(value).ok_or_else(invalid)?),
+        FieldKind::Dropdown => {
+            let tag = value.as_str().ok_or_else(invalid)?;
+            if tag.is_empty() {
+                return Ok(CustomFieldValue::Empty);
+            }
+            let option = find_option(options, tag).ok_or_else(|| unknown_option(tag))?;
+            CustomFieldValue::Dropdown(option.clone())
+        }
+        FieldKind::Multiselect => {
+            let tags = value.as_array().ok_or_else(invalid)?;
+            let options = tags
+                .iter()
+                .map(|tag| {
+                    let tag = tag.as_str().ok_or_else(invalid)?;
+                    find_option(options, tag)
+                        .cloned()
+                        .ok_or_else(|| unknown_option(tag))
+                })
+                .collect::<std::result::Result<Vec<_>, String>>()?;
+            CustomFieldValue::Multiselect(options)
+        }
+        FieldKind::Date => {
+            let date = value.as_str().ok_or_else(invalid)?;
+            let date = date.get(..10).unwrap_or(date);
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| invalid())?;
+            CustomFieldValue::Date(date)
+        }
+        FieldKind::Checkbox => CustomFieldValue::Checkbox(value.as_bool().ok_or_else(invalid)?),
+        FieldKind::Integer => {
+            let integer = match value {
+                serde_json::Value::String(s) => s.trim().parse().ok(),
+                _ => value.as_i64(),
+            };
+            CustomFieldValue::Integer(integer.ok_or_else(invalid)?)
+        }
+        FieldKind::Decimal => {
+            let decimal = match value {
+                serde_json::Value::String(s) => s.trim().parse().ok(),
+                _ => value.as_f64(),
+            };
+            CustomFieldValue::Decimal(decimal.ok_or_else(invalid)?)
+        }
+        FieldKind::Lookup => CustomFieldValue::Lookup(value_as_string(value).ok_or_else(invalid)?),
+        FieldKind::Other => CustomFieldValue::Other(value.clone()),
+    };
+
+    Ok(parsed)
+}
+
+impl CustomFieldValue {
+    /// Interpret a raw value read from Zendesk
+    pub fn parse(
+        kind: FieldKind,
+        title: &str,
+        options: &[CustomFieldOption],
+        value: &serde_json::Value,
+    ) -> Result<Self> {
+        parse_value(kind, title, options, value).map_err(ZendeskError::UnexpectedResponse)
+    }
+
+    /// The raw JSON Zendesk expects when writing this value
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            CustomFieldValue::Empty => serde_json::Value::Null,
+            CustomFieldValue::Text(text) => serde_json::Value::String(text.clone()),
+            CustomFieldValue::Dropdown(option) => serde_json::Value::String(option.value.clone()),
+            CustomFieldValue::Multiselect(options) => serde_json::Value::Array(
+                options
+                    .iter()
+                    .map(|option| serde_json::Value::String(option.value.clone()))
+                    .collect(),
+            ),
+            CustomFieldValue::Date(date) => {
+                serde_json::Value::String(date.format("%Y-%m-%d").to_string())
+            }
+            CustomFieldValue::Checkbox(checked) => serde_json::Value::Bool(*checked),
+            CustomFieldValue::Integer(integer) => serde_json::Value::from(*integer),
+            CustomFieldValue::Decimal(decimal) => serde_json::Value::from(*decimal),
+            CustomFieldValue::Lookup(id) => serde_json::Value::String(id.clone()),
+            CustomFieldValue::Other(value) => value.clone(),
+        }
+    }
+}
+
+/// A user or organization field definition. Both APIs share the same shape and values are
+/// stored on the record under the field's `key`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub key: String,
+
+    #[serde(rename = "type")]
+    pub field_type: String,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regexp_for_validation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_field_options: Option<Vec<CustomFieldOption>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_target_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+pub type OrganizationField = UserField;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserFieldsResponse {
+    pub user_fields: Vec<UserField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationFieldsResponse {
+    pub organization_fields: Vec<OrganizationField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldScope {
+    Ticket,
+    User,
+    Organization,
+}
+
+impl std::fmt::Display for FieldScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldScope::Ticket => write!(f, "ticket"),
+            FieldScope::User => write!(f, "user"),
+            FieldScope::Organization => write!(f, "organization"),
+        }
+    }
+}
+
+/// A field definition as held by [`CustomFieldRegistry`]
+#[derive(Debug, Clone)]
+pub struct RegisteredField {
+    pub id: u64,
+
+    /// The field's `key` for user and organization fields, its title for ticket fields
+    pub key: String,
+
+    pub title: String,
+
+    /// Dynamic content placeholder title of ticket fields, also accepted as a key
+    pub raw_title: Option<String>,
+
+    pub field_type: String,
+    pub kind: FieldKind,
+    pub active: bool,
+    pub options: Vec<CustomFieldOption>,
+}
+
+impl RegisteredField {
+    fn from_ticket_field(field: &TicketField) -> Option<Self> {
+        if field.field_type.is_system() {
+            return None;
+        }
+        Some(Self {
+            id: field.id?,
+            key: field.title.clone(),
+            title: field.title.clone(),
+            raw_title: field.raw_title.clone(),
+            field_type: field.field_type.as_str().to_string(),
+            kind: field.field_type.kind(),
+            active: field.active.unwrap_or(true),
+            options: field.custom_field_options.clone().unwrap_or_default(),
+        })
+    }
+
+    fn from_user_field(field: &UserField) -> Option<Self> {
+        Some(Self {
+            id: field.id?,
+            key: field.key.clone(),
+            title: field.title.clone(),
+            raw_title: None,
+            field_type: field.field_type.clone(),
+            kind: FieldKind::from_type_name(&field.field_type),
+            active: field.active.unwrap_or(true),
+            options: field.custom_field_options.clone().unwrap_or_default(),
+        })
+    }
+
+    fn matches(&self, key: &str) -> bool {
+        self.key.eq_ignore_ascii_case(key)
+            || self
+                .raw_title
+                .as_deref()
+                .is_some_and(|raw| raw.eq_ignore_ascii_case(key))
+            || self.id.to_string() == key
+    }
+
+    /// Interpret a raw value read from Zendesk
+    pub fn parse(&self, value: &serde_json::Value) -> Result<CustomFieldValue> {
+        CustomFieldValue::parse(self.kind, &self.title, &self.options, value)
+    }
+
+    /// Check `input` against this field's type and options and return the JSON to send.
+    /// Dropdown and multiselect options may be given by tag value or display name.
+    pub fn coerce(&self, input: serde_json::Value) -> Result<serde_json::Value> {
+        let by_name = |value: &serde_json::Value| match value.as_str() {
+            Some(name) if find_option(&self.options, name).is_none() => self
+                .options
+                .iter()
+                .find(|option| option.name.eq_ignore_ascii_case(name))
+                .map(|option| serde_json::Value::String(option.value.clone()))
+                .unwrap_or_else(|| value.clone()),
+            _ => value.clone(),
+        };
+
+        let input = match (self.kind, &input) {
+            (FieldKind::Dropdown, _) => by_name(&input),
+            (FieldKind::Multiselect, serde_json::Value::Array(items)) => {
+                serde_json::Value::Array(items.iter().map(by_name).collect())
+            }
+            _ => input,
+        };
+
+        parse_value(self.kind, &self.title, &self.options, &input)
+            .map(|value| value.to_json())
+            .map_err(ZendeskError::validation)
+    }
+}
+
+/// Custom field values read from a record. Values that do not match their definition, such
+/// as a dropdown tag whose option was since removed, are listed in `errors` by field title
+/// instead of failing the whole record.
+#[derive(Debug, Default)]
+pub struct ResolvedFieldValues {
+    pub values: HashMap<String, CustomFieldValue>,
+
+    pub errors: Vec<(String, ZendeskError)>,
+}
+
+/// Custom field definitions for tickets, users and organizations, used to read and write
+/// field values by name instead of numeric id. Rebuild it with
+/// `ZendeskClient::refresh_custom_field_registry` when admins change field definitions.
+#[derive(Debug, Clone)]
+pub struct CustomFieldRegistry {
+    fields: HashMap<FieldScope, Vec<RegisteredField>>,
+    loaded_at: Instant,
+}
+
+impl CustomFieldRegistry {
+    pub fn new(
+        ticket_fields: &[TicketField],
+        user_fields: &[UserField],
+        organization_fields: &[OrganizationField],
+    ) -> Self {
+        let mut fields = HashMap::new();
+        fields.insert(
+            FieldScope::Ticket,
+            ticket_fields
+                .iter()
+                .filter_map(RegisteredField::from_ticket_field)
+                .collect(),
+        );
+        fields.insert(
+            FieldScope::User,
+            user_fields
+                .iter()
+                .filter_map(RegisteredField::from_user_field)
+                .collect(),
+        );
+        fields.insert(
+            FieldScope::Organization,
+            organization_fields
+                .iter()
+                .filter_map(RegisteredField::from_user_field)
+                .collect(),
+        );
+
+        Self {
+            fields,
+            loaded_at: Instant::now(),
+        }
+    }
+
+    pub fn loaded_at(&self) -> Instant {
+        self.loaded_at
+    }
+
+    /// Whether the definitions were loaded more than `max_age` ago
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        self.loaded_at.elapsed() > max_age
+    }
+
+    pub fn fields(&self, scope: FieldScope) -> &[RegisteredField] {
+        self.fields
+            .get(&scope)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Look up a field by key (title for ticket fields, ignoring case) or by numeric id
+    pub fn field(&self, scope: FieldScope, key: &str) -> Result<&RegisteredField> {
+        self.fields(scope)
+            .iter()
+            .find(|field| field.matches(key))
+            .ok_or_else(|| ZendeskError::validation(format!("Unknown {} field '{}'", scope, key)))
+    }
+
+    pub fn field_by_id(&self, scope: FieldScope, id: u64) -> Option<&RegisteredField> {
+        self.fields(scope).iter().find(|field| field.id == id)
+    }
+
+    pub fn ticket_value(&self, ticket: &Ticket, key: &str) -> Result<Option<CustomFieldValue>> {
+        let field = self.field(FieldScope::Ticket, key)?;
+        ticket
+            .custom_field_value(field.id)
+            .map(|value| field.parse(value))
+            .transpose()
+    }
+
+    /// Every custom field value on `ticket` that has a definition, keyed by field title
+    pub fn ticket_values(&self, ticket: &Ticket) -> ResolvedFieldValues {
+        let mut resolved = ResolvedFieldValues::default();
+        for custom_field in ticket.custom_fields.iter().flatten() {
+            let Some(field) = self.field_by_id(FieldScope::Ticket, custom_field.id) else {
+                continue;
+            };
+            match field.parse(&custom_field.value) {
+                Ok(value) => {
+                    resolved.values.insert(field.title.clone(), value);
+                }
+                Err(error) => resolved.errors.push((field.title.clone(), error)),
+            }
+        }
+        resolved
+    }
+
+    pub fn user_value(&self, user: &User, key: &str) -> Result<Option<CustomFieldValue>> {
+        let field = self.field(FieldScope::User, key)?;
+        user.user_fields
+            .as_ref()
+            .and_then(|values| values.get(&field.key))
+            .map(|value| field.parse(value))
+            .transpose()
+    }
+
+    pub fn organization_value(
+        &self,
+        organization: &Organization,
+        key: &str,
+    ) -> Result<Option<CustomFieldValue>> {
+        let field = self.field(FieldScope::Organization, key)?;
+        organization
+            .organization_fields
+            .as_ref()
+            .and_then(|values| values.get(&field.key))
+            .map(|value| field.parse(value))
+            .transpose()
+    }
+
+    /// Build a checked ticket `CustomField` entry for creates and updates
+    pub fn ticket_field(
+        &self,
+        key: &str,
+        value: impl Into<serde_json::Value>,
+    ) -> Result<CustomField> {
+        let field = self.field(FieldScope::Ticket, key)?;
+        Ok(CustomField {
+            id: field.id,
+            value: field.coerce(value.into())?,
+        })
+    }
+
+    /// Build a checked `(key, value)` entry for a user's `user_fields`
+    pub fn user_field(
+        &self,
+        key: &str,
+        value: impl Into<serde_json::Value>,
+    ) -> Result<(String, serde_json::Value)> {
+        let field = self.field(FieldScope::User, key)?;
+        Ok((field.key.clone(), field.coerce(value.into())?))
+    }
+
+    /// Build a checked `(key, value)` entry for an organization's `organization_fields`
+    pub fn organization_field(
+        &self,
+        key: &str,
+        value: impl Into<serde_json::Value>,
+    ) -> Result<(String, serde_json::Value)> {
+        let field = self.field(FieldScope::Organization, key)?;
+        Ok((field.key.clone(), field.coerce(value.into())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn registry() -> CustomFieldRegistry {
+        let ticket_fields: Vec<TicketField> = serde_json::from_value(json!([
+            { "id": 1, "type": "subject", "title": "Subject" },
+            { "id": 360001234, "type": "tagger", "title": "Product", "custom_field_options": [
+                { "id": 10, "name": "Printer", "value": "product_printer" },
+                { "id": 11, "name": "Scanner", "value": "product_scanner" }
+            ]},
+            { "id": 360005678, "type": "integer", "title": "Seats" }
+        ]))
+        .unwrap();
+        let user_fields: Vec<UserField> = serde_json::from_value(json!([
+            { "id": 7, "key": "plan", "type": "dropdown", "title": "Plan", "custom_field_options": [
+                { "id": 70, "name": "Enterprise", "value": "plan_enterprise" }
+            ]},
+            { "id": 8, "key": "renewal", "type": "date", "title": "Renewal" }
+        ]))
+        .unwrap();
+
+        CustomFieldRegistry::new(&ticket_fields, &user_fields, &[])
+    }
+
+    #[test]
+    fn test_set_fields_by_key() {
+        let registry = registry();
+
+        let field = registry.ticket_field("product", "Scanner").unwrap();
+        assert_eq!(field.id, 360001234);
+        assert_eq!(field.value, json!("product_scanner"));
+
+        let field = registry.ticket_field("Seats", "12").unwrap();
+        assert_eq!(field.value, json!(12));
+
+        assert!(registry.ticket_field("Product", "Fax machine").is_err());
+        assert!(registry.ticket_field("Seats", "a dozen").is_err());
+        assert!(registry.ticket_field("Subject", "Hello").is_err());
+        assert!(registry.organization_field("region", "emea").is_err());
+
+        let (key, value) = registry.user_field("plan", "Enterprise").unwrap();
+        assert_eq!(key, "plan");
+        assert_eq!(value, json!("plan_enterprise"));
+    }
+
+    #[test]
+    fn test_read_fields_by_key() {
+        let registry = registry();
+        let user: User = serde_json::from_value(json!({
+            "id": 5,
+            "name": "Jane",
+            "email": "jane@example.com",
+            "user_fields": { "plan": "plan_enterprise", "renewal": "2025-01-31T00:00:00+00:00" }
+        }))
+        .unwrap();
+
+        match registry.user_value(&user, "plan").unwrap() {
+            Some(CustomFieldValue::Dropdown(option)) => assert_eq!(option.name, "Enterprise"),
+            other => panic!("unexpected value {:?}", other),
+        }
+        assert_eq!(
+            registry.user_value(&user, "renewal").unwrap(),
+            Some(CustomFieldValue::Date(
+                NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+            ))
+        );
+    }
+}
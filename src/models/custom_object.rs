@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+pub use crate::models::job_status::{BulkJobResponse, JobStatus};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomObject {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -224,7 +226,7 @@ pub struct CustomObjectField {
     pub url: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomFieldOption {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<u64>,
@@ -501,32 +503,3 @@ pub struct BulkJob {
     pub action: String,
     pub data: serde_json::Value,
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BulkJobResponse {
-    pub job_status: JobStatus,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JobStatus {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub progress: Option<u32>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub total: Option<u32>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub results: Option<serde_json::Value>,
-}
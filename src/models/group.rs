@@ -0,0 +1,204 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An agent group, as returned by the groups endpoints and by search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Group {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether new agents are added to this group by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+
+    /// Private groups hide their tickets from agents outside the group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_public: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupResponse {
+    pub group: Group,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupsResponse {
+    pub groups: Vec<Group>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupRequest {
+    pub group: GroupUpsert,
+}
+
+/// Body for creating or updating a group; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GroupUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_public: Option<bool>,
+}
+
+impl GroupUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn is_default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    pub fn is_public(mut self, is_public: bool) -> Self {
+        self.is_public = Some(is_public);
+        self
+    }
+}
+
+/// Links an agent to a group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembership {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub user_id: u64,
+
+    pub group_id: u64,
+
+    /// Whether this is the agent's default group
+    #[serde(default)]
+    pub default: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembershipResponse {
+    pub group_membership: GroupMembership,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembershipsResponse {
+    pub group_memberships: Vec<GroupMembership>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupMembershipCreate {
+    pub user_id: u64,
+    pub group_id: u64,
+}
+
+impl GroupMembershipCreate {
+    pub fn new(user_id: u64, group_id: u64) -> Self {
+        Self { user_id, group_id }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembershipRequest {
+    pub group_membership: GroupMembershipCreate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembershipsCreateManyRequest {
+    pub group_memberships: Vec<GroupMembershipCreate>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::search::SearchResult;
+    use serde_json::json;
+
+    #[test]
+    fn test_group_shared_with_search() {
+        let result: SearchResult = serde_json::from_value(json!({
+            "result_type": "group",
+            "id": 211,
+            "name": "Tier 2",
+            "default": false,
+            "deleted": false,
+            "is_public": true,
+            "created_at": "2024-01-10T12:00:00Z"
+        }))
+        .unwrap();
+        let SearchResult::Group(group) = result else {
+            panic!("expected a group");
+        };
+        assert_eq!(group.id, Some(211));
+        assert_eq!(group.is_public, Some(true));
+        assert_eq!(
+            group.created_at.unwrap().to_rfc3339(),
+            "2024-01-10T12:00:00+00:00"
+        );
+
+        let body = serde_json::to_value(GroupRequest {
+            group: GroupUpsert::new().name("Tier 3").is_public(false),
+        })
+        .unwrap();
+        assert_eq!(
+            body,
+            json!({ "group": { "name": "Tier 3", "is_public": false } })
+        );
+    }
+}
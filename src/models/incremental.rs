@@ -0,0 +1,189 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::errors::{Result, ZendeskError};
+
+/// Where an incremental export should resume from. Persist this between runs to pick up
+/// exactly where the previous sync stopped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportPosition {
+    /// Unix timestamp in seconds; the only option for time-based exports
+    StartTime(i64),
+    /// Opaque cursor returned by a cursor-based export
+    Cursor(String),
+}
+
+/// One page of an incremental export
+#[derive(Debug, Clone)]
+pub struct IncrementalPage<T> {
+    pub items: Vec<T>,
+
+    /// Side-loaded resources requested with `include`
+    pub sideloaded: HashMap<String, serde_json::Value>,
+
+    /// Position to resume from after this page
+    pub next_position: Option<ExportPosition>,
+
+    pub end_of_stream: bool,
+}
+
+impl<T> IncrementalPage<T> {
+    pub fn sideloaded_resource<U>(&self, resource_name: &str) -> Option<Vec<U>>
+    where
+        U: DeserializeOwned,
+    {
+        self.sideloaded
+            .get(resource_name)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+}
+
+impl<T> IncrementalPage<T>
+where
+    T: DeserializeOwned,
+{
+    const METADATA_KEYS: [&'static str; 8] = [
+        "after_cursor",
+        "before_cursor",
+        "after_url",
+        "before_url",
+        "next_page",
+        "end_time",
+        "end_of_stream",
+        "count",
+    ];
+
+    /// Parse a raw export response whose records live under `resource_key`
+    pub fn from_response(mut response: serde_json::Value, resource_key: &str) -> Result<Self> {
+        let object = response.as_object_mut().ok_or_else(|| {
+            ZendeskError::UnexpectedResponse("Export response is not an object".to_string())
+        })?;
+
+        let items = match object.remove(resource_key) {
+            Some(items) => serde_json::from_value(items)?,
+            None => {
+                return Err(ZendeskError::UnexpectedResponse(format!(
+                    "Export response has no '{}' key",
+                    resource_key
+                )));
+            }
+        };
+
+        // Not every export reports `end_of_stream`; those stop when there is no next page
+        let end_of_stream = match object.get("end_of_stream").and_then(|v| v.as_bool()) {
+            Some(end_of_stream) => end_of_stream,
+            None => object.get("next_page").is_none_or(|v| v.is_null()),
+        };
+
+        let next_position = match object.get("after_cursor").and_then(|v| v.as_str()) {
+            Some(cursor) => Some(ExportPosition::Cursor(cursor.to_string())),
+            None => object
+                .get("end_time")
+                .and_then(|v| v.as_i64())
+                .map(ExportPosition::StartTime),
+        };
+
+        let sideloaded = object
+            .iter()
+            .filter(|(key, _)| !Self::METADATA_KEYS.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        Ok(Self {
+            items,
+            sideloaded,
+            next_position,
+            end_of_stream,
+        })
+    }
+}
+
+/// An entry from the incremental ticket events export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketEvent {
+    pub id: u64,
+    pub ticket_id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updater_id: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merged_ticket_ids: Option<Vec<u64>>,
+
+    #[serde(default)]
+    pub child_events: Vec<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ticket::Ticket;
+    use serde_json::json;
+
+    #[test]
+    fn test_cursor_page_parsing() {
+        let page: IncrementalPage<Ticket> = IncrementalPage::from_response(
+            json!({
+                "tickets": [{ "id": 1, "subject": "Help" }],
+                "users": [{ "id": 5, "name": "Jane", "email": "jane@example.com" }],
+                "after_cursor": "MTU3NjYxMzUzOS4wfHw0Njd8",
+                "after_url": "https://example.zendesk.com/api/v2/incremental/tickets/cursor.json?cursor=MTU3NjYxMzUzOS4wfHw0Njd8",
+                "before_cursor": null,
+                "end_of_stream": true
+            }),
+            "tickets",
+        )
+        .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert!(page.end_of_stream);
+        assert_eq!(
+            page.next_position,
+            Some(ExportPosition::Cursor(
+                "MTU3NjYxMzUzOS4wfHw0Njd8".to_string()
+            ))
+        );
+        assert_eq!(page.sideloaded.len(), 1);
+        assert!(page.sideloaded.contains_key("users"));
+    }
+
+    #[test]
+    fn test_time_based_page_parsing() {
+        let page: IncrementalPage<TicketEvent> = IncrementalPage::from_response(
+            json!({
+                "ticket_events": [{ "id": 926256957613_u64, "ticket_id": 155, "timestamp": 1601357197, "child_events": [] }],
+                "next_page": "https://example.zendesk.com/api/v2/incremental/ticket_events.json?start_time=1601357197",
+                "end_time": 1601357197,
+                "count": 1,
+                "end_of_stream": false
+            }),
+            "ticket_events",
+        )
+        .unwrap();
+
+        assert_eq!(page.items[0].ticket_id, 155);
+        assert!(!page.end_of_stream);
+        assert_eq!(
+            page.next_position,
+            Some(ExportPosition::StartTime(1601357197))
+        );
+        assert!(page.sideloaded.is_empty());
+    }
+}
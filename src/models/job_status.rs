@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkJobResponse {
+    pub job_status: JobStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobStatusesResponse {
+    pub job_statuses: Vec<JobStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<serde_json::Value>,
+}
+
+/// Lifecycle state of a background job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobState {
+    Queued,
+    Working,
+    Completed,
+    Failed,
+    Killed,
+    Unknown(String),
+}
+
+impl JobState {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            JobState::Completed | JobState::Failed | JobState::Killed
+        )
+    }
+}
+
+impl From<&str> for JobState {
+    fn from(value: &str) -> Self {
+        match value {
+            "queued" => JobState::Queued,
+            "working" => JobState::Working,
+            "completed" => JobState::Completed,
+            "failed" => JobState::Failed,
+            "killed" => JobState::Killed,
+            other => JobState::Unknown(other.to_string()),
+        }
+    }
+}
+
+/// Identifier of a record touched by a job; numeric for core resources, text for custom
+/// object records
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JobItemId {
+    Numeric(u64),
+    Text(String),
+}
+
+/// A successfully processed item in a job's `results`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobItemSuccess {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<JobItemId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+/// An item in a job's `results` that Zendesk could not process
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobItemFailure {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<JobItemId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum JobItemResult {
+    Success(JobItemSuccess),
+    Failure(JobItemFailure),
+    /// An entry in a shape this crate does not recognise, kept so it is not mistaken for
+    /// a clean result
+    Unknown(serde_json::Value),
+}
+
+impl JobStatus {
+    /// Parsed lifecycle state, `None` if Zendesk did not report one
+    pub fn state(&self) -> Option<JobState> {
+        self.status.as_deref().map(JobState::from)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state().is_some_and(|state| state.is_finished())
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.state() == Some(JobState::Completed)
+    }
+
+    /// Split the raw `results` array into typed success and failure entries. An entry is
+    /// a failure when it carries an `error` or reports `success: false`; entries that do
+    /// not parse are returned as [`JobItemResult::Unknown`].
+    pub fn item_results(&self) -> Vec<JobItemResult> {
+        let Some(serde_json::Value::Array(items)) = &self.results else {
+            return Vec::new();
+        };
+
+        items
+            .iter()
+            .map(|item| {
+                let failed = item.get("error").is_some()
+                    || item.get("success").and_then(|s| s.as_bool()) == Some(false);
+                let parsed = if failed {
+                    serde_json::from_value(item.clone()).map(JobItemResult::Failure)
+                } else {
+                    serde_json::from_value(item.clone()).map(JobItemResult::Success)
+                };
+                parsed.unwrap_or_else(|_| JobItemResult::Unknown(item.clone()))
+            })
+            .collect()
+    }
+
+    pub fn successes(&self) -> Vec<JobItemSuccess> {
+        self.item_results()
+            .into_iter()
+            .filter_map(|result| match result {
+                JobItemResult::Success(success) => Some(success),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn failures(&self) -> Vec<JobItemFailure> {
+        self.item_results()
+            .into_iter()
+            .filter_map(|result| match result {
+                JobItemResult::Failure(failure) => Some(failure),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Result entries that could not be parsed as a success or failure
+    pub fn unknown_results(&self) -> Vec<serde_json::Value> {
+        self.item_results()
+            .into_iter()
+            .filter_map(|result| match result {
+                JobItemResult::Unknown(value) => Some(value),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_job_state_parsing() {
+        let status: JobStatus = serde_json::from_value(json!({
+            "id": "8b726e606741012ffc2d782bcb7848fe",
+            "status": "working",
+            "progress": 2,
+            "total": 5
+        }))
+        .unwrap();
+
+        assert_eq!(status.state(), Some(JobState::Working));
+        assert!(!status.is_finished());
+    }
+
+    #[test]
+    fn test_item_results_split() {
+        let status: JobStatus = serde_json::from_value(json!({
+            "id": "abc",
+            "status": "completed",
+            "results": [
+                { "id": 244, "index": 0, "action": "update", "success": true, "status": "Updated" },
+                { "id": 245, "index": 1, "error": "TicketUpdateFailed", "details": "Invalid status" },
+                { "id": "01GDXYD7ZTWYP542BA8MDDTE36", "index": 2, "success": false }
+            ]
+        }))
+        .unwrap();
+
+        assert!(status.is_completed());
+        let successes = status.successes();
+        let failures = status.failures();
+        assert_eq!(successes.len(), 1);
+        assert_eq!(successes[0].id, Some(JobItemId::Numeric(244)));
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].error.as_deref(), Some("TicketUpdateFailed"));
+        assert_eq!(
+            failures[1].id,
+            Some(JobItemId::Text("01GDXYD7ZTWYP542BA8MDDTE36".to_string()))
+        );
+        assert!(status.unknown_results().is_empty());
+    }
+
+    #[test]
+    fn test_item_results_keep_unparseable_entries() {
+        let status: JobStatus = serde_json::from_value(json!({
+            "id": "abc",
+            "status": "completed",
+            "results": [
+                { "id": 244, "index": 0, "success": true },
+                { "id": 245, "index": "first", "error": "PermissionDenied" },
+                "unexpected"
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(status.item_results().len(), 3);
+        assert_eq!(status.successes().len(), 1);
+        assert!(status.failures().is_empty());
+        assert_eq!(
+            status.unknown_results(),
+            vec![
+                json!({ "id": 245, "index": "first", "error": "PermissionDenied" }),
+                json!("unexpected")
+            ]
+        );
+    }
+}
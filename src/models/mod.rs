@@ -1,15 +1,55 @@
+pub mod attachment;
+pub mod automation;
+pub mod business_rule;
+pub mod custom_field;
 pub mod custom_object;
+pub mod group;
+pub mod incremental;
+pub mod job_status;
 pub mod organization;
+pub mod redaction;
 pub mod relationship;
+pub mod satisfaction_rating;
+pub mod schedule;
 pub mod search;
+pub mod sla_policy;
 pub mod ticket;
+pub mod ticket_audit;
+pub mod ticket_field;
+pub mod ticket_import;
+pub mod ticket_macro;
+pub mod ticket_metric;
+pub mod trigger;
 pub mod user;
+pub mod user_identity;
+pub mod user_merge;
+pub mod view;
 
 // Re-exports for convenience
+pub use attachment::*;
+pub use automation::*;
+pub use business_rule::*;
+pub use custom_field::*;
 pub use custom_object::*;
+pub use group::*;
+pub use incremental::*;
+pub use job_status::*;
 pub use relationship::*;
 
 pub use organization::*;
-pub use search::{SearchResponse, SearchResult};
+pub use redaction::*;
+pub use satisfaction_rating::*;
+pub use schedule::*;
+pub use search::{SearchResponse, SearchResult, SearchResultType};
+pub use sla_policy::*;
 pub use ticket::*;
+pub use ticket_audit::*;
+pub use ticket_field::*;
+pub use ticket_import::*;
+pub use ticket_macro::*;
+pub use ticket_metric::*;
+pub use trigger::*;
 pub use user::*;
+pub use user_identity::*;
+pub use user_merge::*;
+pub use view::*;
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // Import CustomField from ticket module for consistency
 use crate::models::ticket::CustomField;
@@ -25,6 +26,10 @@ pub struct Organization {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomField>>,
 
+    /// Custom organization field values keyed by field key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_fields: Option<HashMap<String, serde_json::Value>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_id: Option<String>,
 
@@ -62,6 +67,10 @@ pub struct OrganizationCreate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomField>>,
 
+    /// Custom organization field values keyed by field key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_fields: Option<HashMap<String, serde_json::Value>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_id: Option<String>,
 }
@@ -106,6 +115,7 @@ impl OrganizationBuilder {
                 domain_names: None,
                 tags: None,
                 custom_fields: None,
+                organization_fields: None,
                 external_id: None,
             },
         }
@@ -136,6 +146,16 @@ impl OrganizationBuilder {
         self
     }
 
+    /// Set a custom organization field; see `CustomFieldRegistry::organization_field` for
+    /// checked values
+    pub fn organization_field(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.organization
+            .organization_fields
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value);
+        self
+    }
+
     pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
         self.organization.external_id = Some(external_id.into());
         self
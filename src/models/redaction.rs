@@ -0,0 +1,147 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::models::ticket::TicketComment;
+
+/// Body for `tickets/{ticket_id}/comments/{comment_id}/redact`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentRedactionRequest {
+    pub text: String,
+}
+
+/// Body for the agent workspace `comment_redactions/{comment_id}` endpoint. Wrap the
+/// text to remove in `<redact>` tags inside `html_body`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentRedactionUpdate {
+    pub ticket_id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_attachment_urls: Option<Vec<String>>,
+}
+
+impl CommentRedactionUpdate {
+    pub fn new(ticket_id: u64) -> Self {
+        Self {
+            ticket_id,
+            html_body: None,
+            external_attachment_urls: None,
+        }
+    }
+
+    pub fn html_body(mut self, html_body: impl Into<String>) -> Self {
+        self.html_body = Some(html_body.into());
+        self
+    }
+
+    pub fn external_attachment_urls(mut self, urls: Vec<String>) -> Self {
+        self.external_attachment_urls = Some(urls);
+        self
+    }
+}
+
+/// A string found in a comment that should be redacted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingRedaction {
+    pub ticket_id: u64,
+    pub comment_id: u64,
+    pub text: String,
+}
+
+/// Collect every distinct match of `patterns` in each comment's body, one entry per
+/// comment and matched string. Overlapping matches are merged into a single string so no
+/// redaction removes text another one still needs, and longer strings come first within a
+/// comment. Comments without an id are skipped.
+pub fn find_redactions(
+    ticket_id: u64,
+    comments: &[TicketComment],
+    patterns: &[Regex],
+) -> Vec<PendingRedaction> {
+    let mut redactions: Vec<PendingRedaction> = Vec::new();
+
+    for comment in comments {
+        let Some(comment_id) = comment.id else {
+            continue;
+        };
+
+        let mut ranges: Vec<(usize, usize)> = patterns
+            .iter()
+            .flat_map(|pattern| pattern.find_iter(&comment.body))
+            .filter(|found| !found.is_empty())
+            .map(|found| (found.start(), found.end()))
+            .collect();
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(usize, usize)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start < last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        let first = redactions.len();
+        for (start, end) in merged {
+            let redaction = PendingRedaction {
+                ticket_id,
+                comment_id,
+                text: comment.body[start..end].to_string(),
+            };
+            if !redactions[first..].contains(&redaction) {
+                redactions.push(redaction);
+            }
+        }
+        redactions[first..].sort_by_key(|redaction| std::cmp::Reverse(redaction.text.len()));
+    }
+
+    redactions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_find_redactions() {
+        let comments: Vec<TicketComment> = serde_json::from_value(json!([
+            { "id": 1, "body": "My card is 4111 1111 1111 1111, call me on 555-0100" },
+            { "id": 2, "body": "Nothing sensitive here" },
+            { "id": 3, "body": "Card 4111 1111 1111 1111 again and 4111 1111 1111 1111" }
+        ]))
+        .unwrap();
+
+        let patterns = vec![
+            Regex::new(r"\b(?:\d{4} ){3}\d{4}\b").unwrap(),
+            Regex::new(r"\b555-\d{4}\b").unwrap(),
+        ];
+
+        let redactions = find_redactions(42, &comments, &patterns);
+        assert_eq!(redactions.len(), 3);
+        assert_eq!(redactions[0].comment_id, 1);
+        assert_eq!(redactions[0].text, "4111 1111 1111 1111");
+        assert_eq!(redactions[1].text, "555-0100");
+        assert_eq!(redactions[2].comment_id, 3);
+    }
+
+    #[test]
+    fn test_find_redactions_merges_overlapping_matches() {
+        let comments: Vec<TicketComment> = serde_json::from_value(json!([
+            { "id": 1, "body": "Account 12345678, PIN 1234" }
+        ]))
+        .unwrap();
+        let patterns = vec![
+            Regex::new(r"\d{8}").unwrap(),
+            Regex::new(r"Account \d{4}").unwrap(),
+            Regex::new(r"\b\d{4}\b").unwrap(),
+        ];
+
+        let texts: Vec<String> = find_redactions(42, &comments, &patterns)
+            .into_iter()
+            .map(|redaction| redaction.text)
+            .collect();
+        assert_eq!(texts, vec!["Account 12345678", "1234"]);
+    }
+}
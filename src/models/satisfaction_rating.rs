@@ -0,0 +1,351 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::query::CursorPagination;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SatisfactionScore {
+    /// Survey sent, no answer yet
+    Offered,
+    /// Survey not sent
+    Unoffered,
+    Good,
+    Bad,
+    /// A score this crate does not know yet
+    #[serde(other)]
+    Unknown,
+}
+
+/// Score filter accepted by `GET satisfaction_ratings?score=...`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SatisfactionScoreFilter {
+    Offered,
+    Unoffered,
+    Received,
+    ReceivedWithComment,
+    ReceivedWithoutComment,
+    Good,
+    GoodWithComment,
+    GoodWithoutComment,
+    Bad,
+    BadWithComment,
+    BadWithoutComment,
+}
+
+impl SatisfactionScoreFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SatisfactionScoreFilter::Offered => "offered",
+            SatisfactionScoreFilter::Unoffered => "unoffered",
+            SatisfactionScoreFilter::Received => "received",
+            SatisfactionScoreFilter::ReceivedWithComment => "received_with_comment",
+            SatisfactionScoreFilter::ReceivedWithoutComment => "received_without_comment",
+            SatisfactionScoreFilter::Good => "good",
+            SatisfactionScoreFilter::GoodWithComment => "good_with_comment",
+            SatisfactionScoreFilter::GoodWithoutComment => "good_without_comment",
+            SatisfactionScoreFilter::Bad => "bad",
+            SatisfactionScoreFilter::BadWithComment => "bad_with_comment",
+            SatisfactionScoreFilter::BadWithoutComment => "bad_without_comment",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionRating {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub score: SatisfactionScore,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requester_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason_code: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionRatingResponse {
+    pub satisfaction_rating: SatisfactionRating,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionRatingsResponse {
+    pub satisfaction_ratings: Vec<SatisfactionRating>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<CursorPagination>,
+}
+
+impl SatisfactionRatingsResponse {
+    /// Cursor for the next page, if there is one
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.meta
+            .as_ref()
+            .filter(|meta| meta.has_more)
+            .and_then(|meta| meta.after_cursor.as_deref())
+    }
+}
+
+/// Filters for listing satisfaction ratings
+#[derive(Debug, Clone, Default)]
+pub struct SatisfactionRatingFilter {
+    pub score: Option<SatisfactionScoreFilter>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+impl SatisfactionRatingFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn score(mut self, score: SatisfactionScoreFilter) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    pub fn between(mut self, start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub(crate) fn query_params(&self) -> Vec<String> {
+        let mut params = Vec::new();
+        if let Some(score) = self.score {
+            params.push(format!("score={}", score.as_str()));
+        }
+        if let Some(start_time) = self.start_time {
+            params.push(format!("start_time={}", start_time.timestamp()));
+        }
+        if let Some(end_time) = self.end_time {
+            params.push(format!("end_time={}", end_time.timestamp()));
+        }
+        params
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionRatingCreateRequest {
+    pub satisfaction_rating: SatisfactionRatingCreate,
+}
+
+/// A rating submitted on behalf of the ticket's requester
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionRatingCreate {
+    pub score: SatisfactionScore,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason_code: Option<u32>,
+}
+
+impl SatisfactionRatingCreate {
+    pub fn new(score: SatisfactionScore) -> Self {
+        Self {
+            score,
+            comment: None,
+            reason_code: None,
+        }
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    pub fn reason_code(mut self, reason_code: u32) -> Self {
+        self.reason_code = Some(reason_code);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionReason {
+    pub id: u64,
+
+    pub reason_code: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionReasonResponse {
+    pub reason: SatisfactionReason,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionReasonsResponse {
+    pub reasons: Vec<SatisfactionReason>,
+}
+
+/// Rating counts for one slice of a CSAT report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CsatSummary {
+    pub good: u64,
+    pub bad: u64,
+    pub offered: u64,
+    pub unoffered: u64,
+}
+
+impl CsatSummary {
+    /// Scores this crate does not know are left out of every count
+    fn add(&mut self, score: SatisfactionScore) {
+        match score {
+            SatisfactionScore::Good => self.good += 1,
+            SatisfactionScore::Bad => self.bad += 1,
+            SatisfactionScore::Offered => self.offered += 1,
+            SatisfactionScore::Unoffered => self.unoffered += 1,
+            SatisfactionScore::Unknown => {}
+        }
+    }
+
+    pub fn responses(&self) -> u64 {
+        self.good + self.bad
+    }
+
+    /// Share of good ratings among answered surveys, `None` without answers
+    pub fn csat(&self) -> Option<f64> {
+        let responses = self.responses();
+        (responses > 0).then(|| self.good as f64 / responses as f64)
+    }
+
+    /// Share of sent surveys that were answered, `None` if none were sent
+    pub fn response_rate(&self) -> Option<f64> {
+        let sent = self.responses() + self.offered;
+        (sent > 0).then(|| self.responses() as f64 / sent as f64)
+    }
+}
+
+/// CSAT totals overall and per assignee and group
+#[derive(Debug, Clone, Default)]
+pub struct CsatReport {
+    pub overall: CsatSummary,
+    pub by_assignee: HashMap<u64, CsatSummary>,
+    pub by_group: HashMap<u64, CsatSummary>,
+}
+
+impl CsatReport {
+    pub fn from_ratings<'a>(ratings: impl IntoIterator<Item = &'a SatisfactionRating>) -> Self {
+        let mut report = Self::default();
+        for rating in ratings {
+            report.add(rating);
+        }
+        report
+    }
+
+    pub fn add(&mut self, rating: &SatisfactionRating) {
+        self.overall.add(rating.score);
+        if let Some(assignee_id) = rating.assignee_id {
+            self.by_assignee
+                .entry(assignee_id)
+                .or_default()
+                .add(rating.score);
+        }
+        if let Some(group_id) = rating.group_id {
+            self.by_group.entry(group_id).or_default().add(rating.score);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_csat_report() {
+        let ratings: Vec<SatisfactionRating> = serde_json::from_value(json!([
+            { "id": 1, "score": "good", "assignee_id": 10, "group_id": 100, "created_at": "2024-01-02T10:00:00Z" },
+            { "id": 2, "score": "bad", "assignee_id": 10, "group_id": 100, "reason_code": 5 },
+            { "id": 3, "score": "good", "assignee_id": 11, "group_id": 100 },
+            { "id": 4, "score": "offered", "assignee_id": 11, "group_id": 200 },
+            { "id": 5, "score": "neutral", "assignee_id": 11, "group_id": 200 }
+        ]))
+        .unwrap();
+
+        assert_eq!(ratings[4].score, SatisfactionScore::Unknown);
+
+        let report = CsatReport::from_ratings(&ratings);
+        assert_eq!(report.overall.responses(), 3);
+        assert_eq!(report.by_assignee[&10].csat(), Some(0.5));
+        assert_eq!(report.by_assignee[&11].response_rate(), Some(0.5));
+        assert_eq!(report.by_group[&100].good, 2);
+        assert_eq!(report.by_group[&200].csat(), None);
+    }
+
+    #[test]
+    fn test_filter_query_params() {
+        let start = DateTime::from_timestamp(1704067200, 0).unwrap();
+        let end = DateTime::from_timestamp(1706745600, 0).unwrap();
+        let filter = SatisfactionRatingFilter::new()
+            .score(SatisfactionScoreFilter::BadWithComment)
+            .between(start, end);
+
+        assert_eq!(
+            filter.query_params(),
+            vec![
+                "score=bad_with_comment",
+                "start_time=1704067200",
+                "end_time=1706745600"
+            ]
+        );
+    }
+}
@@ -0,0 +1,550 @@
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, ZendeskError};
+
+/// A business hours schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Schedule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub name: String,
+
+    /// Rails time zone name such as "Pacific Time (US & Canada)"
+    pub time_zone: String,
+
+    #[serde(default)]
+    pub intervals: Vec<ScheduleInterval>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// An open period in minutes since Sunday 00:00 in the schedule's time zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleInterval {
+    pub start_time: u32,
+    pub end_time: u32,
+}
+
+impl ScheduleInterval {
+    /// Open from `start` to `end` on `weekday`
+    pub fn on(weekday: Weekday, start: NaiveTime, end: NaiveTime) -> Self {
+        let day = weekday.num_days_from_sunday() * MINUTES_PER_DAY;
+        let minutes = |time: NaiveTime| time.signed_duration_since(NaiveTime::MIN).num_minutes();
+        Self {
+            start_time: day + minutes(start) as u32,
+            end_time: day + minutes(end) as u32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleResponse {
+    pub schedule: Schedule,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchedulesResponse {
+    pub schedules: Vec<Schedule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleRequest {
+    pub schedule: ScheduleUpsert,
+}
+
+/// Body for creating or updating a schedule. Open hours are set separately with
+/// `update_schedule_workweek`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScheduleUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+}
+
+impl ScheduleUpsert {
+    pub fn new(name: impl Into<String>, time_zone: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            time_zone: Some(time_zone.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workweek {
+    pub intervals: Vec<ScheduleInterval>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkweekRequest {
+    pub workweek: Workweek,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkweekResponse {
+    pub workweek: Workweek,
+}
+
+/// Days on which a schedule is closed, inclusive of both dates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holiday {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub name: String,
+
+    pub start_date: NaiveDate,
+
+    pub end_date: NaiveDate,
+}
+
+impl Holiday {
+    pub fn new(name: impl Into<String>, start_date: NaiveDate, end_date: NaiveDate) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+            start_date,
+            end_date,
+        }
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayRequest {
+    pub holiday: Holiday,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayResponse {
+    pub holiday: Holiday,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidaysResponse {
+    pub holidays: Vec<Holiday>,
+}
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Give up looking for open hours after this many days, e.g. for a schedule without any
+const MAX_SEARCH_DAYS: u32 = 3 * 366;
+
+/// Local business-time arithmetic for a schedule, matching how Zendesk measures SLA
+/// targets in business hours: only open hours on non-holiday days in the schedule's
+/// time zone count.
+#[derive(Debug, Clone)]
+pub struct BusinessCalendar {
+    time_zone: Tz,
+    /// Open periods per weekday, Sunday first, in minutes since local midnight
+    week: [Vec<(u32, u32)>; 7],
+    holidays: Vec<Holiday>,
+}
+
+impl BusinessCalendar {
+    pub fn new(schedule: &Schedule, holidays: &[Holiday]) -> Result<Self> {
+        let time_zone = resolve_time_zone(&schedule.time_zone).ok_or_else(|| {
+            ZendeskError::validation(format!("unknown time zone \"{}\"", schedule.time_zone))
+        })?;
+        Ok(Self::from_parts(time_zone, &schedule.intervals, holidays))
+    }
+
+    pub fn from_parts(time_zone: Tz, intervals: &[ScheduleInterval], holidays: &[Holiday]) -> Self {
+        let mut week: [Vec<(u32, u32)>; 7] = Default::default();
+        for interval in intervals {
+            // Split intervals that run past midnight into one period per day
+            let mut start = interval.start_time;
+            while start < interval.end_time {
+                let day = start / MINUTES_PER_DAY;
+                let day_start = day * MINUTES_PER_DAY;
+                let end = interval.end_time.min(day_start + MINUTES_PER_DAY);
+                week[(day % 7) as usize].push((start - day_start, end - day_start));
+                start = end;
+            }
+        }
+        for periods in &mut week {
+            periods.sort_unstable();
+        }
+
+        Self {
+            time_zone,
+            week,
+            holidays: holidays.to_vec(),
+        }
+    }
+
+    pub fn time_zone(&self) -> Tz {
+        self.time_zone
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.iter().any(|holiday| holiday.contains(date))
+    }
+
+    /// Whether `at` falls within open hours
+    pub fn is_open(&self, at: DateTime<Utc>) -> bool {
+        let date = at.with_timezone(&self.time_zone).date_naive();
+        self.open_periods(date)
+            .any(|(start, end)| start <= at && at < end)
+    }
+
+    /// Business time between two instants; zero if `end` is not after `start`
+    pub fn elapsed(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Duration {
+        if end <= start {
+            return Duration::zero();
+        }
+
+        // Start a day early so periods that began before `start` in local time are seen
+        let first = start.with_timezone(&self.time_zone).date_naive() - Duration::days(1);
+        let last = end.with_timezone(&self.time_zone).date_naive();
+        first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .flat_map(|date| self.open_periods(date))
+            .map(|(open, close)| {
+                let overlap = close.min(end) - open.max(start);
+                overlap.max(Duration::zero())
+            })
+            .sum()
+    }
+
+    /// Whole business minutes between two instants, as Zendesk reports them
+    pub fn elapsed_minutes(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        self.elapsed(start, end).num_minutes()
+    }
+
+    /// The instant `duration` of business time after `start`, e.g. when an SLA target
+    /// measured in business hours breaches. `None` if the schedule has no open hours.
+    pub fn add(&self, start: DateTime<Utc>, duration: Duration) -> Option<DateTime<Utc>> {
+        if duration <= Duration::zero() {
+            return Some(start);
+        }
+
+        let mut remaining = duration;
+        let first = start.with_timezone(&self.time_zone).date_naive() - Duration::days(1);
+        for date in first.iter_days().take(MAX_SEARCH_DAYS as usize) {
+            for (open, close) in self.open_periods(date) {
+                let open = open.max(start);
+                if close <= open {
+                    continue;
+                }
+                let available = close - open;
+                if remaining <= available {
+                    return Some(open + remaining);
+                }
+                remaining -= available;
+            }
+        }
+        None
+    }
+
+    /// Open periods on a local date as UTC instants
+    fn open_periods(
+        &self,
+        date: NaiveDate,
+    ) -> impl Iterator<Item = (DateTime<Utc>, DateTime<Utc>)> {
+        let periods: &[(u32, u32)] = if self.is_holiday(date) {
+            &[]
+        } else {
+            &self.week[date.weekday().num_days_from_sunday() as usize]
+        };
+        let midnight = date.and_time(NaiveTime::MIN);
+        periods.iter().map(move |(start, end)| {
+            (
+                self.to_utc(midnight + Duration::minutes(*start as i64)),
+                self.to_utc(midnight + Duration::minutes(*end as i64)),
+            )
+        })
+    }
+
+    /// Local wall-clock time to UTC. Ambiguous times take the earlier instant and times
+    /// skipped by a DST change move forward by the size of the gap.
+    fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        self.time_zone
+            .from_local_datetime(&local)
+            .earliest()
+            .or_else(|| {
+                self.time_zone
+                    .from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+            })
+            .map(|time| time.with_timezone(&Utc))
+            .unwrap_or_else(|| local.and_utc())
+    }
+}
+
+/// Resolve a Zendesk (Rails) time zone name such as "Eastern Time (US & Canada)", or an
+/// IANA name such as "America/New_York"
+pub fn resolve_time_zone(name: &str) -> Option<Tz> {
+    RAILS_TIME_ZONES
+        .iter()
+        .find(|(rails, _)| *rails == name)
+        .map_or(name, |(_, iana)| *iana)
+        .parse()
+        .ok()
+}
+
+/// Rails time zone names, as used by Zendesk, and the IANA zone each one maps to
+const RAILS_TIME_ZONES: &[(&str, &str)] = &[
+    ("International Date Line West", "Etc/GMT+12"),
+    ("Midway Island", "Pacific/Midway"),
+    ("American Samoa", "Pacific/Pago_Pago"),
+    ("Hawaii", "Pacific/Honolulu"),
+    ("Alaska", "America/Juneau"),
+    ("Pacific Time (US & Canada)", "America/Los_Angeles"),
+    ("Tijuana", "America/Tijuana"),
+    ("Mountain Time (US & Canada)", "America/Denver"),
+    ("Arizona", "America/Phoenix"),
+    ("Chihuahua", "America/Chihuahua"),
+    ("Mazatlan", "America/Mazatlan"),
+    ("Central Time (US & Canada)", "America/Chicago"),
+    ("Saskatchewan", "America/Regina"),
+    ("Guadalajara", "America/Mexico_City"),
+    ("Mexico City", "America/Mexico_City"),
+    ("Monterrey", "America/Monterrey"),
+    ("Central America", "America/Guatemala"),
+    ("Eastern Time (US & Canada)", "America/New_York"),
+    ("Indiana (East)", "America/Indiana/Indianapolis"),
+    ("Bogota", "America/Bogota"),
+    ("Lima", "America/Lima"),
+    ("Quito", "America/Lima"),
+    ("Atlantic Time (Canada)", "America/Halifax"),
+    ("Caracas", "America/Caracas"),
+    ("La Paz", "America/La_Paz"),
+    ("Santiago", "America/Santiago"),
+    ("Newfoundland", "America/St_Johns"),
+    ("Brasilia", "America/Sao_Paulo"),
+    ("Buenos Aires", "America/Argentina/Buenos_Aires"),
+    ("Montevideo", "America/Montevideo"),
+    ("Georgetown", "America/Guyana"),
+    ("Puerto Rico", "America/Puerto_Rico"),
+    ("Greenland", "America/Godthab"),
+    ("Mid-Atlantic", "Atlantic/South_Georgia"),
+    ("Azores", "Atlantic/Azores"),
+    ("Cape Verde Is.", "Atlantic/Cape_Verde"),
+    ("Dublin", "Europe/Dublin"),
+    ("Edinburgh", "Europe/London"),
+    ("Lisbon", "Europe/Lisbon"),
+    ("London", "Europe/London"),
+    ("Casablanca", "Africa/Casablanca"),
+    ("Monrovia", "Africa/Monrovia"),
+    ("UTC", "Etc/UTC"),
+    ("Belgrade", "Europe/Belgrade"),
+    ("Bratislava", "Europe/Bratislava"),
+    ("Budapest", "Europe/Budapest"),
+    ("Ljubljana", "Europe/Ljubljana"),
+    ("Prague", "Europe/Prague"),
+    ("Sarajevo", "Europe/Sarajevo"),
+    ("Skopje", "Europe/Skopje"),
+    ("Warsaw", "Europe/Warsaw"),
+    ("Zagreb", "Europe/Zagreb"),
+    ("Brussels", "Europe/Brussels"),
+    ("Copenhagen", "Europe/Copenhagen"),
+    ("Madrid", "Europe/Madrid"),
+    ("Paris", "Europe/Paris"),
+    ("Amsterdam", "Europe/Amsterdam"),
+    ("Berlin", "Europe/Berlin"),
+    ("Bern", "Europe/Zurich"),
+    ("Zurich", "Europe/Zurich"),
+    ("Rome", "Europe/Rome"),
+    ("Stockholm", "Europe/Stockholm"),
+    ("Vienna", "Europe/Vienna"),
+    ("West Central Africa", "Africa/Algiers"),
+    ("Bucharest", "Europe/Bucharest"),
+    ("Cairo", "Africa/Cairo"),
+    ("Helsinki", "Europe/Helsinki"),
+    ("Kyiv", "Europe/Kiev"),
+    ("Riga", "Europe/Riga"),
+    ("Sofia", "Europe/Sofia"),
+    ("Tallinn", "Europe/Tallinn"),
+    ("Vilnius", "Europe/Vilnius"),
+    ("Athens", "Europe/Athens"),
+    ("Istanbul", "Europe/Istanbul"),
+    ("Minsk", "Europe/Minsk"),
+    ("Jerusalem", "Asia/Jerusalem"),
+    ("Harare", "Africa/Harare"),
+    ("Pretoria", "Africa/Johannesburg"),
+    ("Kaliningrad", "Europe/Kaliningrad"),
+    ("Moscow", "Europe/Moscow"),
+    ("St. Petersburg", "Europe/Moscow"),
+    ("Volgograd", "Europe/Volgograd"),
+    ("Samara", "Europe/Samara"),
+    ("Kuwait", "Asia/Kuwait"),
+    ("Riyadh", "Asia/Riyadh"),
+    ("Nairobi", "Africa/Nairobi"),
+    ("Baghdad", "Asia/Baghdad"),
+    ("Tehran", "Asia/Tehran"),
+    ("Abu Dhabi", "Asia/Muscat"),
+    ("Muscat", "Asia/Muscat"),
+    ("Baku", "Asia/Baku"),
+    ("Tbilisi", "Asia/Tbilisi"),
+    ("Yerevan", "Asia/Yerevan"),
+    ("Kabul", "Asia/Kabul"),
+    ("Ekaterinburg", "Asia/Yekaterinburg"),
+    ("Islamabad", "Asia/Karachi"),
+    ("Karachi", "Asia/Karachi"),
+    ("Tashkent", "Asia/Tashkent"),
+    ("Chennai", "Asia/Kolkata"),
+    ("Kolkata", "Asia/Kolkata"),
+    ("Mumbai", "Asia/Kolkata"),
+    ("New Delhi", "Asia/Kolkata"),
+    ("Kathmandu", "Asia/Kathmandu"),
+    ("Astana", "Asia/Dhaka"),
+    ("Dhaka", "Asia/Dhaka"),
+    ("Sri Jayawardenepura", "Asia/Colombo"),
+    ("Almaty", "Asia/Almaty"),
+    ("Novosibirsk", "Asia/Novosibirsk"),
+    ("Rangoon", "Asia/Rangoon"),
+    ("Bangkok", "Asia/Bangkok"),
+    ("Hanoi", "Asia/Bangkok"),
+    ("Jakarta", "Asia/Jakarta"),
+    ("Krasnoyarsk", "Asia/Krasnoyarsk"),
+    ("Beijing", "Asia/Shanghai"),
+    ("Chongqing", "Asia/Chongqing"),
+    ("Hong Kong", "Asia/Hong_Kong"),
+    ("Urumqi", "Asia/Urumqi"),
+    ("Kuala Lumpur", "Asia/Kuala_Lumpur"),
+    ("Singapore", "Asia/Singapore"),
+    ("Taipei", "Asia/Taipei"),
+    ("Perth", "Australia/Perth"),
+    ("Irkutsk", "Asia/Irkutsk"),
+    ("Ulaanbaatar", "Asia/Ulaanbaatar"),
+    ("Seoul", "Asia/Seoul"),
+    ("Osaka", "Asia/Tokyo"),
+    ("Sapporo", "Asia/Tokyo"),
+    ("Tokyo", "Asia/Tokyo"),
+    ("Yakutsk", "Asia/Yakutsk"),
+    ("Darwin", "Australia/Darwin"),
+    ("Adelaide", "Australia/Adelaide"),
+    ("Canberra", "Australia/Melbourne"),
+    ("Melbourne", "Australia/Melbourne"),
+    ("Sydney", "Australia/Sydney"),
+    ("Brisbane", "Australia/Brisbane"),
+    ("Hobart", "Australia/Hobart"),
+    ("Vladivostok", "Asia/Vladivostok"),
+    ("Guam", "Pacific/Guam"),
+    ("Port Moresby", "Pacific/Port_Moresby"),
+    ("Magadan", "Asia/Magadan"),
+    ("Srednekolymsk", "Asia/Srednekolymsk"),
+    ("Solomon Is.", "Pacific/Guadalcanal"),
+    ("New Caledonia", "Pacific/Noumea"),
+    ("Fiji", "Pacific/Fiji"),
+    ("Kamchatka", "Asia/Kamchatka"),
+    ("Marshall Is.", "Pacific/Majuro"),
+    ("Auckland", "Pacific/Auckland"),
+    ("Wellington", "Pacific/Auckland"),
+    ("Nuku'alofa", "Pacific/Tongatapu"),
+    ("Tokelau Is.", "Pacific/Fakaofo"),
+    ("Chatham Is.", "Pacific/Chatham"),
+    ("Samoa", "Pacific/Apia"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    /// Monday to Friday, 09:00 to 17:00 in New York, closed on 2024-07-04
+    fn calendar() -> BusinessCalendar {
+        let schedule: Schedule = serde_json::from_value(json!({
+            "id": 1,
+            "name": "East Coast",
+            "time_zone": "Eastern Time (US & Canada)",
+            "intervals": [
+                { "start_time": 1980, "end_time": 2460 },
+                { "start_time": 3420, "end_time": 3900 },
+                { "start_time": 4860, "end_time": 5340 },
+                { "start_time": 6300, "end_time": 6780 },
+                { "start_time": 7740, "end_time": 8220 }
+            ]
+        }))
+        .unwrap();
+        let holidays: Vec<Holiday> = serde_json::from_value(json!([
+            { "id": 5, "name": "Independence Day", "start_date": "2024-07-04", "end_date": "2024-07-04" }
+        ]))
+        .unwrap();
+        BusinessCalendar::new(&schedule, &holidays).unwrap()
+    }
+
+    #[test]
+    fn test_interval_on() {
+        let interval = ScheduleInterval::on(
+            Weekday::Mon,
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            interval,
+            ScheduleInterval {
+                start_time: 1980,
+                end_time: 2460
+            }
+        );
+    }
+
+    #[test]
+    fn test_elapsed_skips_nights_weekends_and_holidays() {
+        let calendar = calendar();
+        // Wednesday 2024-07-03 16:00 EDT to Monday 2024-07-08 10:00 EDT:
+        // 1h Wednesday, Thursday is a holiday, 8h Friday, 1h Monday
+        let start = utc("2024-07-03T20:00:00Z");
+        let end = utc("2024-07-08T14:00:00Z");
+        assert_eq!(calendar.elapsed_minutes(start, end), 10 * 60);
+        assert_eq!(calendar.elapsed(end, start), Duration::zero());
+
+        assert!(calendar.is_open(utc("2024-07-05T13:00:00Z")));
+        assert!(!calendar.is_open(utc("2024-07-04T15:00:00Z")));
+    }
+
+    #[test]
+    fn test_add_business_time() {
+        let calendar = calendar();
+        // Friday 2024-07-05 16:30 EDT plus 2 business hours is Monday 10:30 EDT
+        let due = calendar
+            .add(utc("2024-07-05T20:30:00Z"), Duration::hours(2))
+            .unwrap();
+        assert_eq!(due, utc("2024-07-08T14:30:00Z"));
+
+        let closed = BusinessCalendar::from_parts(Tz::UTC, &[], &[]);
+        assert_eq!(
+            closed.add(utc("2024-07-05T20:30:00Z"), Duration::hours(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_elapsed_across_dst_change() {
+        let calendar = calendar();
+        // Friday 2024-03-08 09:00 EST to Monday 2024-03-11 17:00 EDT
+        let start = utc("2024-03-08T14:00:00Z");
+        let end = utc("2024-03-11T21:00:00Z");
+        assert_eq!(calendar.elapsed_minutes(start, end), 16 * 60);
+    }
+
+    #[test]
+    fn test_resolve_time_zone() {
+        for (rails, _) in RAILS_TIME_ZONES {
+            assert!(resolve_time_zone(rails).is_some(), "{}", rails);
+        }
+        assert_eq!(resolve_time_zone("Europe/Berlin"), Some(Tz::Europe__Berlin));
+        assert_eq!(resolve_time_zone("Atlantis"), None);
+    }
+}
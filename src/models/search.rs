@@ -1,3 +1,6 @@
+use crate::errors::Result;
+use crate::models::custom_field::CustomFieldRegistry;
+pub use crate::models::group::Group;
 use crate::models::{organization::Organization, ticket::Ticket, user::User};
 use crate::query::SortOrder;
 use serde::{Deserialize, Serialize};
@@ -21,6 +24,7 @@ pub struct SearchResponse {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "result_type")]
+#[allow(clippy::large_enum_variant)]
 pub enum SearchResult {
     #[serde(rename = "ticket")]
     Ticket(Ticket),
@@ -35,15 +39,81 @@ pub enum SearchResult {
     Group(Group),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Group {
-    pub id: u64,
-    pub name: String,
-    pub description: Option<String>,
-    pub default: Option<bool>,
-    pub deleted: Option<bool>,
-    pub created_at: Option<String>,
-    pub updated_at: Option<String>,
+impl SearchResult {
+    /// The result type and id, used to recognise the same record across pages
+    pub fn identity(&self) -> Option<(SearchResultType, u64)> {
+        match self {
+            SearchResult::Ticket(ticket) => ticket.id.map(|id| (SearchResultType::Ticket, id)),
+            SearchResult::User(user) => user.id.map(|id| (SearchResultType::User, id)),
+            SearchResult::Organization(org) => {
+                org.id.map(|id| (SearchResultType::Organization, id))
+            }
+            SearchResult::Group(group) => group.id.map(|id| (SearchResultType::Group, id)),
+        }
+    }
+}
+
+/// Resource type filter used by `search/export` and the unified search stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchResultType {
+    Ticket,
+    User,
+    Organization,
+    Group,
+}
+
+impl SearchResultType {
+    /// Prefix the query with `type:<resource>` unless it already filters by this type.
+    /// Negated filters such as `-type:ticket` and text inside quoted phrases do not count.
+    pub fn scope_query(&self, query: &str) -> String {
+        let resource = self.to_string();
+        let scoped = query_terms(query).into_iter().any(|term| {
+            term.split_once(':').is_some_and(|(key, value)| {
+                key.eq_ignore_ascii_case("type")
+                    && value.trim_matches('"').eq_ignore_ascii_case(&resource)
+            })
+        });
+        if scoped {
+            query.to_string()
+        } else {
+            format!("type:{} {}", resource, query)
+        }
+    }
+}
+
+/// Split a search query into its terms on whitespace outside double-quoted phrases
+fn query_terms(query: &str) -> Vec<&str> {
+    let mut terms = Vec::new();
+    let mut start = None;
+    let mut in_quotes = false;
+    for (index, c) in query.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        if c.is_whitespace() && !in_quotes {
+            if let Some(term_start) = start.take() {
+                terms.push(&query[term_start..index]);
+            }
+        } else if start.is_none() {
+            start = Some(index);
+        }
+    }
+    if let Some(term_start) = start {
+        terms.push(&query[term_start..]);
+    }
+    terms
+}
+
+impl std::fmt::Display for SearchResultType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchResultType::Ticket => write!(f, "ticket"),
+            SearchResultType::User => write!(f, "user"),
+            SearchResultType::Organization => write!(f, "organization"),
+            SearchResultType::Group => write!(f, "group"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +125,9 @@ pub struct SearchCountResponse {
 pub struct SearchExportResponse {
     pub results: Vec<SearchResult>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<SearchExportMeta>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub after_cursor: Option<String>,
 
@@ -71,6 +144,29 @@ pub struct SearchExportResponse {
     pub end_of_stream: Option<bool>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchExportMeta {
+    pub has_more: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_cursor: Option<String>,
+}
+
+impl SearchExportResponse {
+    /// Cursor for the next export page, or `None` once the export is exhausted
+    pub fn next_cursor(&self) -> Option<&str> {
+        match &self.meta {
+            Some(meta) if !meta.has_more => None,
+            Some(meta) => meta.after_cursor.as_deref(),
+            None if self.end_of_stream == Some(true) => None,
+            None => self.after_cursor.as_deref(),
+        }
+    }
+}
+
 // Search query builder utilities
 #[derive(Debug, Clone)]
 pub struct SearchQueryBuilder {
@@ -199,6 +295,22 @@ impl SearchQueryBuilder {
         self
     }
 
+    /// Match a ticket custom field by name, checking `value` against the field definition.
+    /// Dropdown values may be given by option name.
+    pub fn custom_field_by_key(
+        self,
+        registry: &CustomFieldRegistry,
+        key: &str,
+        value: &str,
+    ) -> Result<Self> {
+        let field = registry.ticket_field(key, value)?;
+        let value = match field.value {
+            serde_json::Value::String(value) => value,
+            other => other.to_string(),
+        };
+        Ok(self.custom_field(field.id, &value))
+    }
+
     // Raw query part
     pub fn raw(mut self, query_part: &str) -> Self {
         self.query_parts.push(query_part.to_string());
@@ -251,3 +363,73 @@ impl std::fmt::Display for SortOrder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_scope_query() {
+        assert_eq!(
+            SearchResultType::Ticket.scope_query("status:open"),
+            "type:ticket status:open"
+        );
+        assert_eq!(
+            SearchResultType::User.scope_query("type:user role:agent"),
+            "type:user role:agent"
+        );
+        assert_eq!(
+            SearchResultType::Ticket.scope_query("Type:Ticket status:open"),
+            "Type:Ticket status:open"
+        );
+        assert_eq!(
+            SearchResultType::Ticket.scope_query("-type:ticket status:open"),
+            "type:ticket -type:ticket status:open"
+        );
+        assert_eq!(
+            SearchResultType::Ticket.scope_query("\"type:ticket missing\""),
+            "type:ticket \"type:ticket missing\""
+        );
+        assert_eq!(
+            SearchResultType::Ticket.scope_query("subject:\"printer on fire\" type:ticket"),
+            "subject:\"printer on fire\" type:ticket"
+        );
+    }
+
+    #[test]
+    fn test_query_terms() {
+        assert_eq!(
+            query_terms("  status:open subject:\"two words\" \"a b\"-x "),
+            vec!["status:open", "subject:\"two words\"", "\"a b\"-x"]
+        );
+    }
+
+    #[test]
+    fn test_search_result_identity() {
+        let result: SearchResult = serde_json::from_value(json!({
+            "result_type": "group",
+            "id": 42,
+            "name": "Support"
+        }))
+        .unwrap();
+        assert_eq!(result.identity(), Some((SearchResultType::Group, 42)));
+    }
+
+    #[test]
+    fn test_export_next_cursor() {
+        let response: SearchExportResponse = serde_json::from_value(json!({
+            "results": [],
+            "meta": { "has_more": true, "after_cursor": "abc", "before_cursor": null }
+        }))
+        .unwrap();
+        assert_eq!(response.next_cursor(), Some("abc"));
+
+        let response: SearchExportResponse = serde_json::from_value(json!({
+            "results": [],
+            "meta": { "has_more": false, "after_cursor": "abc", "before_cursor": null }
+        }))
+        .unwrap();
+        assert_eq!(response.next_cursor(), None);
+    }
+}
@@ -0,0 +1,253 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::business_rule::RuleConditions;
+use crate::models::ticket::TicketPriority;
+
+/// The metric an SLA target applies to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum SlaMetric {
+    FirstReplyTime,
+    NextReplyTime,
+    PeriodicUpdateTime,
+    PausableUpdateTime,
+    RequesterWaitTime,
+    AgentWorkTime,
+    TotalResolutionTime,
+    GroupOwnershipTime,
+    Other(String),
+}
+
+impl SlaMetric {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SlaMetric::FirstReplyTime => "first_reply_time",
+            SlaMetric::NextReplyTime => "next_reply_time",
+            SlaMetric::PeriodicUpdateTime => "periodic_update_time",
+            SlaMetric::PausableUpdateTime => "pausable_update_time",
+            SlaMetric::RequesterWaitTime => "requester_wait_time",
+            SlaMetric::AgentWorkTime => "agent_work_time",
+            SlaMetric::TotalResolutionTime => "total_resolution_time",
+            SlaMetric::GroupOwnershipTime => "group_ownership_time",
+            SlaMetric::Other(other) => other,
+        }
+    }
+}
+
+impl From<String> for SlaMetric {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "first_reply_time" => SlaMetric::FirstReplyTime,
+            "next_reply_time" => SlaMetric::NextReplyTime,
+            "periodic_update_time" => SlaMetric::PeriodicUpdateTime,
+            "pausable_update_time" => SlaMetric::PausableUpdateTime,
+            "requester_wait_time" => SlaMetric::RequesterWaitTime,
+            "agent_work_time" => SlaMetric::AgentWorkTime,
+            "total_resolution_time" => SlaMetric::TotalResolutionTime,
+            "group_ownership_time" => SlaMetric::GroupOwnershipTime,
+            _ => SlaMetric::Other(value),
+        }
+    }
+}
+
+impl From<SlaMetric> for String {
+    fn from(value: SlaMetric) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+/// A target for one metric at one priority
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlaPolicyMetric {
+    pub priority: TicketPriority,
+
+    pub metric: SlaMetric,
+
+    /// Target in minutes
+    pub target: u64,
+
+    /// Whether the target is measured in business hours rather than calendar hours
+    pub business_hours: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_in_seconds: Option<u64>,
+}
+
+impl SlaPolicyMetric {
+    pub fn new(priority: TicketPriority, metric: SlaMetric, target: u64) -> Self {
+        Self {
+            priority,
+            metric,
+            target,
+            business_hours: false,
+            target_in_seconds: None,
+        }
+    }
+
+    pub fn business_hours(mut self, business_hours: bool) -> Self {
+        self.business_hours = business_hours;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPolicy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    /// Tickets matching these conditions get this policy
+    #[serde(default)]
+    pub filter: RuleConditions,
+
+    #[serde(default)]
+    pub policy_metrics: Vec<SlaPolicyMetric>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl SlaPolicy {
+    /// The target for `metric` on tickets of `priority`, if the policy sets one
+    pub fn target(&self, priority: TicketPriority, metric: &SlaMetric) -> Option<&SlaPolicyMetric> {
+        self.policy_metrics
+            .iter()
+            .find(|target| target.priority == priority && target.metric == *metric)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPolicyResponse {
+    pub sla_policy: SlaPolicy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPoliciesResponse {
+    pub sla_policies: Vec<SlaPolicy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPolicyRequest {
+    pub sla_policy: SlaPolicyUpsert,
+}
+
+/// Body for creating or updating an SLA policy; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SlaPolicyUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<RuleConditions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_metrics: Option<Vec<SlaPolicyMetric>>,
+}
+
+impl SlaPolicyUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn filter(mut self, filter: RuleConditions) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn metric(mut self, metric: SlaPolicyMetric) -> Self {
+        self.policy_metrics
+            .get_or_insert_with(Vec::new)
+            .push(metric);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPolicyReorderRequest {
+    pub sla_policy_ids: Vec<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::business_rule::RuleCondition;
+    use serde_json::json;
+
+    #[test]
+    fn test_sla_policy_deserialize() {
+        let policy: SlaPolicy = serde_json::from_value(json!({
+            "id": 36,
+            "title": "Incidents",
+            "position": 3,
+            "filter": {
+                "all": [{ "field": "type", "operator": "is", "value": "incident" }],
+                "any": []
+            },
+            "policy_metrics": [
+                { "priority": "normal", "metric": "first_reply_time", "target": 30, "business_hours": false },
+                { "priority": "urgent", "metric": "first_reply_time", "target": 10, "business_hours": true }
+            ],
+            "created_at": "2015-03-17T22:50:26Z"
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            policy.filter.all[0],
+            RuleCondition::TicketType { .. }
+        ));
+        let target = policy
+            .target(TicketPriority::Urgent, &SlaMetric::FirstReplyTime)
+            .unwrap();
+        assert_eq!(target.target, 10);
+        assert!(target.business_hours);
+        assert!(
+            policy
+                .target(TicketPriority::High, &SlaMetric::FirstReplyTime)
+                .is_none()
+        );
+    }
+}
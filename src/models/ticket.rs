@@ -1,12 +1,22 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::satisfaction_rating::SatisfactionScore;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ticket {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
     pub subject: String,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_subject: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
@@ -17,11 +27,15 @@ pub struct Ticket {
     pub priority: Option<TicketPriority>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type", alias = "ticket_type")]
     pub ticket_type: Option<TicketType>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requester_id: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitter_id: Option<u64>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assignee_id: Option<u64>,
 
@@ -31,20 +45,184 @@ pub struct Ticket {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub organization_id: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collaborator_ids: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_ids: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_cc_ids: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub problem_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_incidents: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followup_ids: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_form_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_status_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forum_topic_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing_agreement_ids: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_public: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_attachments: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_channelback: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_messaging_channel: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<Via>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub satisfaction_rating: Option<TicketSatisfactionRating>,
+
+    /// SLA targets, only present when the ticket was fetched with `include=slas`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slas: Option<TicketSlas>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomField>>,
 
+    /// Legacy alias of `custom_fields` that Zendesk still returns
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub fields: Option<Vec<CustomField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_timestamp: Option<i64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+
+    /// Fields this crate does not model yet, kept so they survive a read-modify-write
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// How a ticket or event was created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Via {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<ViaSource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViaSource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rel: Option<String>,
+}
+
+/// The satisfaction rating summary embedded in a ticket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketSatisfactionRating {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<SatisfactionScore>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketSlas {
+    #[serde(default)]
+    pub policy_metrics: Vec<TicketSlaMetric>,
+}
+
+/// A single SLA target applied to a ticket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketSlaMetric {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breach_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minutes: Option<i64>,
+}
+
+impl Ticket {
+    /// Value of a custom field by id, looking at both `custom_fields` and `fields`
+    pub fn custom_field_value(&self, field_id: u64) -> Option<&serde_json::Value> {
+        self.custom_fields
+            .iter()
+            .chain(self.fields.iter())
+            .flatten()
+            .find(|field| field.id == field_id)
+            .map(|field| &field.value)
+    }
+
+    /// The earliest upcoming SLA breach, if the ticket was fetched with `include=slas`
+    pub fn next_sla_breach(&self) -> Option<DateTime<Utc>> {
+        self.slas
+            .as_ref()?
+            .policy_metrics
+            .iter()
+            .filter(|metric| metric.stage.as_deref() == Some("active"))
+            .filter_map(|metric| metric.breach_at)
+            .min()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -139,7 +317,7 @@ pub enum TicketStatus {
     Closed,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TicketPriority {
     Low,
@@ -148,7 +326,7 @@ pub enum TicketPriority {
     Urgent,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TicketType {
     Problem,
@@ -171,6 +349,7 @@ pub struct TicketCreate {
     pub priority: Option<TicketPriority>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type", alias = "ticket_type")]
     pub ticket_type: Option<TicketType>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -262,12 +441,91 @@ pub struct TicketUpdate {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remove_tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_ccs: Option<Vec<TicketParticipantChange>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers: Option<Vec<TicketParticipantChange>>,
+}
+
+/// Whether a participant change adds or removes the user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParticipantAction {
+    Put,
+    Delete,
+}
+
+/// An entry in a ticket update's `email_ccs` or `followers` list. Users are matched by id,
+/// or by email for people who may not have a Zendesk user yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketParticipantChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+
+    pub action: ParticipantAction,
+}
+
+impl TicketParticipantChange {
+    pub fn add_user(user_id: u64) -> Self {
+        Self {
+            user_id: Some(user_id),
+            user_email: None,
+            user_name: None,
+            action: ParticipantAction::Put,
+        }
+    }
+
+    pub fn remove_user(user_id: u64) -> Self {
+        Self {
+            user_id: Some(user_id),
+            user_email: None,
+            user_name: None,
+            action: ParticipantAction::Delete,
+        }
+    }
+
+    pub fn add_email(email: impl Into<String>) -> Self {
+        Self {
+            user_id: None,
+            user_email: Some(email.into()),
+            user_name: None,
+            action: ParticipantAction::Put,
+        }
+    }
+
+    pub fn remove_email(email: impl Into<String>) -> Self {
+        Self {
+            user_id: None,
+            user_email: Some(email.into()),
+            user_name: None,
+            action: ParticipantAction::Delete,
+        }
+    }
+
+    /// Name used if Zendesk has to create a user for an email CC
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.user_name = Some(name.into());
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TicketCommentCreate {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub body: String,
 
+    /// HTML version of the comment; Zendesk uses it instead of `body` when both are set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public: Option<bool>,
 
@@ -278,6 +536,302 @@ pub struct TicketCommentCreate {
     pub uploads: Option<Vec<String>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketCreateManyRequest {
+    pub tickets: Vec<TicketCreate>,
+}
+
+/// Changes applied by `tickets/update_many`, either to every listed ticket or, when `id`
+/// is set, to a single ticket in a per-ticket batch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketBulkUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<TicketCommentCreate>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TicketStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TicketPriority>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub ticket_type: Option<TicketType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub problem_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_ccs: Option<Vec<TicketParticipantChange>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers: Option<Vec<TicketParticipantChange>>,
+}
+
+impl TicketBulkUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn for_ticket(ticket_id: u64) -> Self {
+        Self {
+            id: Some(ticket_id),
+            ..Self::default()
+        }
+    }
+
+    pub fn comment(mut self, comment: TicketCommentCreate) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    pub fn status(mut self, status: TicketStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn priority(mut self, priority: TicketPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn ticket_type(mut self, ticket_type: TicketType) -> Self {
+        self.ticket_type = Some(ticket_type);
+        self
+    }
+
+    pub fn assignee_id(mut self, assignee_id: u64) -> Self {
+        self.assignee_id = Some(assignee_id);
+        self
+    }
+
+    pub fn group_id(mut self, group_id: u64) -> Self {
+        self.group_id = Some(group_id);
+        self
+    }
+
+    /// Link an incident to its problem ticket
+    pub fn problem_id(mut self, problem_id: u64) -> Self {
+        self.problem_id = Some(problem_id);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    pub fn additional_tags(mut self, tags: Vec<String>) -> Self {
+        self.additional_tags = Some(tags);
+        self
+    }
+
+    pub fn remove_tags(mut self, tags: Vec<String>) -> Self {
+        self.remove_tags = Some(tags);
+        self
+    }
+
+    pub fn email_ccs(mut self, changes: Vec<TicketParticipantChange>) -> Self {
+        self.email_ccs = Some(changes);
+        self
+    }
+
+    pub fn followers(mut self, changes: Vec<TicketParticipantChange>) -> Self {
+        self.followers = Some(changes);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketUpdateManyRequest {
+    pub ticket: TicketBulkUpdate,
+}
+
+/// Changes written to a single ticket with `PUT tickets/{ticket_id}`. Unlike `TicketUpdate`
+/// the comment is optional.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<TicketCommentCreate>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TicketStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TicketPriority>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub ticket_type: Option<TicketType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub problem_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_ccs: Option<Vec<TicketParticipantChange>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers: Option<Vec<TicketParticipantChange>>,
+
+    /// Other writable ticket properties, sent as-is
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl TicketChanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn comment(mut self, comment: TicketCommentCreate) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    pub fn status(mut self, status: TicketStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn priority(mut self, priority: TicketPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn ticket_type(mut self, ticket_type: TicketType) -> Self {
+        self.ticket_type = Some(ticket_type);
+        self
+    }
+
+    pub fn assignee_id(mut self, assignee_id: u64) -> Self {
+        self.assignee_id = Some(assignee_id);
+        self
+    }
+
+    pub fn group_id(mut self, group_id: u64) -> Self {
+        self.group_id = Some(group_id);
+        self
+    }
+
+    /// Link an incident to its problem ticket
+    pub fn problem_id(mut self, problem_id: u64) -> Self {
+        self.problem_id = Some(problem_id);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    pub fn custom_fields(mut self, custom_fields: Vec<CustomField>) -> Self {
+        self.custom_fields = Some(custom_fields);
+        self
+    }
+
+    pub fn email_ccs(mut self, changes: Vec<TicketParticipantChange>) -> Self {
+        self.email_ccs = Some(changes);
+        self
+    }
+
+    pub fn followers(mut self, changes: Vec<TicketParticipantChange>) -> Self {
+        self.followers = Some(changes);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketChangesRequest {
+    pub ticket: TicketChanges,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketBatchUpdateRequest {
+    pub tickets: Vec<TicketBulkUpdate>,
+}
+
+/// Body for `tickets/{ticket_id}/merge`; `ids` are the source tickets merged into the target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketMergeRequest {
+    pub ids: Vec<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_comment: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_comment_is_public: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_comment: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_comment_is_public: Option<bool>,
+}
+
+impl TicketMergeRequest {
+    pub fn new(source_ticket_ids: Vec<u64>) -> Self {
+        Self {
+            ids: source_ticket_ids,
+            source_comment: None,
+            source_comment_is_public: None,
+            target_comment: None,
+            target_comment_is_public: None,
+        }
+    }
+
+    /// Comment added to each source ticket as it is closed
+    pub fn source_comment(mut self, comment: impl Into<String>, is_public: bool) -> Self {
+        self.source_comment = Some(comment.into());
+        self.source_comment_is_public = Some(is_public);
+        self
+    }
+
+    /// Comment added to the target ticket
+    pub fn target_comment(mut self, comment: impl Into<String>, is_public: bool) -> Self {
+        self.target_comment = Some(comment.into());
+        self.target_comment_is_public = Some(is_public);
+        self
+    }
+}
+
 impl Ticket {
     pub fn builder(subject: impl Into<String>) -> TicketBuilder {
         TicketBuilder::new(subject)
@@ -292,6 +846,7 @@ impl TicketCommentCreate {
     pub fn public_response(body: impl Into<String>) -> Self {
         Self {
             body: body.into(),
+            html_body: None,
             public: Some(true),
             author_id: None,
             uploads: None,
@@ -301,6 +856,7 @@ impl TicketCommentCreate {
     pub fn work_note(body: impl Into<String>) -> Self {
         Self {
             body: body.into(),
+            html_body: None,
             public: Some(false),
             author_id: None,
             uploads: None,
@@ -403,6 +959,7 @@ impl TicketCommentBuilder {
         Self {
             comment: TicketCommentCreate {
                 body: body.into(),
+                html_body: None,
                 public: Some(true), // Default to public
                 author_id: None,
                 uploads: None,
@@ -435,6 +992,11 @@ impl TicketCommentBuilder {
         self
     }
 
+    pub fn html_body(mut self, html_body: impl Into<String>) -> Self {
+        self.comment.html_body = Some(html_body.into());
+        self
+    }
+
     pub fn build(self) -> TicketCommentCreate {
         self.comment
     }
@@ -450,6 +1012,8 @@ impl TicketCommentBuilder {
                 tags: None,
                 additional_tags: None,
                 remove_tags: None,
+                email_ccs: None,
+                followers: None,
             },
         }
     }
@@ -472,10 +1036,13 @@ impl TicketCommentBuilder {
                 tags,
                 additional_tags: None,
                 remove_tags: None,
+                email_ccs: None,
+                followers: None,
             },
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn build_request_with_all_updates(
         self,
         status: Option<TicketStatus>,
@@ -496,7 +1063,66 @@ impl TicketCommentBuilder {
                 tags,
                 additional_tags,
                 remove_tags,
+                email_ccs: None,
+                followers: None,
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ticket_full_deserialize() {
+        let ticket: Ticket = serde_json::from_value(json!({
+            "id": 35436,
+            "subject": "Help, my printer is on fire!",
+            "raw_subject": "{{dc.printer_on_fire}}",
+            "status": "open",
+            "type": "incident",
+            "problem_id": 9873,
+            "brand_id": 1,
+            "ticket_form_id": 2,
+            "is_public": true,
+            "sharing_agreement_ids": [84432],
+            "followup_ids": [],
+            "via": { "channel": "web", "source": { "from": {}, "to": {}, "rel": null } },
+            "satisfaction_rating": { "id": 1234, "score": "good", "comment": "Great support!" },
+            "slas": {
+                "policy_metrics": [
+                    { "metric": "first_reply_time", "stage": "active", "breach_at": "2024-05-01T12:00:00Z", "minutes": 60 },
+                    { "metric": "next_reply_time", "stage": "achieved", "breach_at": null }
+                ]
+            },
+            "fields": [{ "id": 27642, "value": "745" }],
+            "due_at": null,
+            "created_at": "2009-07-20T22:55:29Z",
+            "updated_at": "2011-05-05T10:38:52Z",
+            "some_future_field": { "enabled": true }
+        }))
+        .unwrap();
+
+        assert_eq!(ticket.ticket_type, Some(TicketType::Incident));
+        assert_eq!(ticket.via.as_ref().unwrap().channel.as_deref(), Some("web"));
+        assert_eq!(
+            ticket.created_at.unwrap().to_rfc3339(),
+            "2009-07-20T22:55:29+00:00"
+        );
+        assert_eq!(
+            ticket.satisfaction_rating.as_ref().unwrap().score,
+            Some(SatisfactionScore::Good)
+        );
+        assert_eq!(ticket.custom_field_value(27642), Some(&json!("745")));
+        assert_eq!(
+            ticket.next_sla_breach().unwrap().to_rfc3339(),
+            "2024-05-01T12:00:00+00:00"
+        );
+
+        let round_trip = serde_json::to_value(&ticket).unwrap();
+        assert_eq!(round_trip["some_future_field"], json!({ "enabled": true }));
+        assert_eq!(round_trip["created_at"], json!("2009-07-20T22:55:29Z"));
+    }
+}
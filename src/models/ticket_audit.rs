@@ -0,0 +1,383 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::models::ticket::CommentAttachment;
+use crate::query::CursorPagination;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketAudit {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+
+    #[serde(default)]
+    pub events: Vec<AuditEvent>,
+}
+
+impl TicketAudit {
+    /// `Change` events in this audit that touched the given field (e.g. "priority")
+    pub fn changes_to<'a>(&'a self, field_name: &'a str) -> impl Iterator<Item = &'a ChangeEvent> {
+        self.events.iter().filter_map(move |event| match event {
+            AuditEvent::Change(change) if change.field_name == field_name => Some(change),
+            _ => None,
+        })
+    }
+
+    /// The comment added in this audit, if any
+    pub fn comment(&self) -> Option<&CommentEvent> {
+        self.events.iter().find_map(|event| match event {
+            AuditEvent::Comment(comment) => Some(comment),
+            _ => None,
+        })
+    }
+}
+
+/// A single event inside a ticket audit, keyed on the event's `type`. Event types this
+/// crate does not model, or payloads that fail to parse, are kept as `Other`.
+#[derive(Debug, Clone)]
+pub enum AuditEvent {
+    Comment(CommentEvent),
+    VoiceComment(VoiceCommentEvent),
+    Change(ChangeEvent),
+    Create(CreateEvent),
+    Notification(NotificationEvent),
+    Cc(CcEvent),
+    CommentPrivacyChange(CommentPrivacyChangeEvent),
+    SatisfactionRating(SatisfactionRatingEvent),
+    Error(ErrorEvent),
+    Other(serde_json::Value),
+}
+
+impl AuditEvent {
+    /// The event's `type` string as sent by Zendesk
+    pub fn event_type(&self) -> &str {
+        match self {
+            AuditEvent::Comment(_) => "Comment",
+            AuditEvent::VoiceComment(_) => "VoiceComment",
+            AuditEvent::Change(_) => "Change",
+            AuditEvent::Create(_) => "Create",
+            AuditEvent::Notification(_) => "Notification",
+            AuditEvent::Cc(_) => "Cc",
+            AuditEvent::CommentPrivacyChange(_) => "CommentPrivacyChange",
+            AuditEvent::SatisfactionRating(_) => "SatisfactionRating",
+            AuditEvent::Error(_) => "Error",
+            AuditEvent::Other(value) => value
+                .get("type")
+                .and_then(|t| t.as_str())
+                .unwrap_or("Unknown"),
+        }
+    }
+
+    fn from_value(value: serde_json::Value) -> Self {
+        let parsed = match value.get("type").and_then(|t| t.as_str()) {
+            Some("Comment") => serde_json::from_value(value.clone()).map(AuditEvent::Comment),
+            Some("VoiceComment") => {
+                serde_json::from_value(value.clone()).map(AuditEvent::VoiceComment)
+            }
+            Some("Change") => serde_json::from_value(value.clone()).map(AuditEvent::Change),
+            Some("Create") => serde_json::from_value(value.clone()).map(AuditEvent::Create),
+            Some("Notification") => {
+                serde_json::from_value(value.clone()).map(AuditEvent::Notification)
+            }
+            Some("Cc") => serde_json::from_value(value.clone()).map(AuditEvent::Cc),
+            Some("CommentPrivacyChange") => {
+                serde_json::from_value(value.clone()).map(AuditEvent::CommentPrivacyChange)
+            }
+            Some("SatisfactionRating") => {
+                serde_json::from_value(value.clone()).map(AuditEvent::SatisfactionRating)
+            }
+            Some("Error") => serde_json::from_value(value.clone()).map(AuditEvent::Error),
+            _ => return AuditEvent::Other(value),
+        };
+        parsed.unwrap_or(AuditEvent::Other(value))
+    }
+
+    fn to_value(&self) -> serde_json::Result<serde_json::Value> {
+        let mut value = match self {
+            AuditEvent::Comment(event) => serde_json::to_value(event)?,
+            AuditEvent::VoiceComment(event) => serde_json::to_value(event)?,
+            AuditEvent::Change(event) => serde_json::to_value(event)?,
+            AuditEvent::Create(event) => serde_json::to_value(event)?,
+            AuditEvent::Notification(event) => serde_json::to_value(event)?,
+            AuditEvent::Cc(event) => serde_json::to_value(event)?,
+            AuditEvent::CommentPrivacyChange(event) => serde_json::to_value(event)?,
+            AuditEvent::SatisfactionRating(event) => serde_json::to_value(event)?,
+            AuditEvent::Error(event) => serde_json::to_value(event)?,
+            AuditEvent::Other(value) => return Ok(value.clone()),
+        };
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "type".to_string(),
+                serde_json::Value::String(self.event_type().to_string()),
+            );
+        }
+        Ok(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuditEvent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Ok(AuditEvent::from_value(value))
+    }
+}
+
+impl Serialize for AuditEvent {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_value()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentEvent {
+    pub id: u64,
+
+    #[serde(default)]
+    pub body: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plain_body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<CommentAttachment>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceCommentEvent {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_from: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcription_visible: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<CommentAttachment>>,
+}
+
+/// A field value changed by the audit. Values are kept as JSON because their shape
+/// depends on the field (strings, arrays of tags, numbers, null).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeEvent {
+    pub id: u64,
+    pub field_name: String,
+
+    #[serde(default)]
+    pub value: serde_json::Value,
+
+    #[serde(default)]
+    pub previous_value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEvent {
+    pub id: u64,
+    pub field_name: String,
+
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationEvent {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipients: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CcEvent {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipients: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentPrivacyChangeEvent {
+    pub id: u64,
+    pub comment_id: u64,
+    pub public: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatisfactionRatingEvent {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorEvent {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketAuditResponse {
+    pub audit: TicketAudit,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketAuditsResponse {
+    pub audits: Vec<TicketAudit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<CursorPagination>,
+}
+
+impl TicketAuditsResponse {
+    /// Cursor for the next page of account-wide audits, if there is one
+    pub fn next_cursor(&self) -> Option<&str> {
+        match &self.meta {
+            Some(meta) if meta.has_more => meta.after_cursor.as_deref(),
+            Some(_) => None,
+            None => self.after_cursor.as_deref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_audit_event_variants() {
+        let audit: TicketAudit = serde_json::from_value(json!({
+            "id": 35436,
+            "ticket_id": 47,
+            "author_id": 5246746,
+            "created_at": "2009-07-20T22:55:29Z",
+            "events": [
+                { "id": 1564245, "type": "Comment", "body": "Thanks!", "public": true, "author_id": 5246746 },
+                { "id": 1564246, "type": "Change", "field_name": "priority", "value": "high", "previous_value": "normal" },
+                { "id": 1564247, "type": "Notification", "subject": "Updated", "recipients": [1, 2] },
+                { "id": 1564248, "type": "TicketSharingEvent", "agreement_id": 9 }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            audit.created_at.unwrap().to_rfc3339(),
+            "2009-07-20T22:55:29+00:00"
+        );
+        assert_eq!(audit.events.len(), 4);
+        assert_eq!(audit.comment().unwrap().body, "Thanks!");
+
+        let changes: Vec<_> = audit.changes_to("priority").collect();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].previous_value, json!("normal"));
+
+        assert!(matches!(audit.events[3], AuditEvent::Other(_)));
+        assert_eq!(audit.events[3].event_type(), "TicketSharingEvent");
+    }
+
+    #[test]
+    fn test_audit_event_round_trip() {
+        let original = json!({
+            "id": 7,
+            "type": "Change",
+            "field_name": "status",
+            "value": "solved",
+            "previous_value": "open"
+        });
+        let event: AuditEvent = serde_json::from_value(original.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&event).unwrap(), original);
+    }
+}
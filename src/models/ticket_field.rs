@@ -0,0 +1,883 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::errors::Result;
+use crate::models::custom_field::{
+    CustomFieldRegistry, CustomFieldValue, FieldKind, FieldScope, ResolvedFieldValues,
+};
+use crate::models::custom_object::{CreateCustomFieldOption, CustomFieldOption};
+use crate::models::ticket::Ticket;
+
+/// The `type` of a ticket field. System fields (subject, status, ...) and custom fields
+/// share the same endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TicketFieldType {
+    Subject,
+    Description,
+    Status,
+    CustomStatus,
+    TicketType,
+    Priority,
+    Group,
+    Assignee,
+    Text,
+    Textarea,
+    Checkbox,
+    Date,
+    Integer,
+    Decimal,
+    Regexp,
+    PartialCreditCard,
+    Multiselect,
+    Tagger,
+    Lookup,
+    Other(String),
+}
+
+impl TicketFieldType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TicketFieldType::Subject => "subject",
+            TicketFieldType::Description => "description",
+            TicketFieldType::Status => "status",
+            TicketFieldType::CustomStatus => "custom_status",
+            TicketFieldType::TicketType => "tickettype",
+            TicketFieldType::Priority => "priority",
+            TicketFieldType::Group => "group",
+            TicketFieldType::Assignee => "assignee",
+            TicketFieldType::Text => "text",
+            TicketFieldType::Textarea => "textarea",
+            TicketFieldType::Checkbox => "checkbox",
+            TicketFieldType::Date => "date",
+            TicketFieldType::Integer => "integer",
+            TicketFieldType::Decimal => "decimal",
+            TicketFieldType::Regexp => "regexp",
+            TicketFieldType::PartialCreditCard => "partialcreditcard",
+            TicketFieldType::Multiselect => "multiselect",
+            TicketFieldType::Tagger => "tagger",
+            TicketFieldType::Lookup => "lookup",
+            TicketFieldType::Other(other) => other,
+        }
+    }
+
+    /// How values of this field are interpreted
+    pub fn kind(&self) -> FieldKind {
+        FieldKind::from_type_name(self.as_str())
+    }
+
+    /// Whether the field is one of Zendesk's built-in ticket properties
+    pub fn is_system(&self) -> bool {
+        matches!(
+            self,
+            TicketFieldType::Subject
+                | TicketFieldType::Description
+                | TicketFieldType::Status
+                | TicketFieldType::CustomStatus
+                | TicketFieldType::TicketType
+                | TicketFieldType::Priority
+                | TicketFieldType::Group
+                | TicketFieldType::Assignee
+        )
+    }
+}
+
+impl From<String> for TicketFieldType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "subject" => TicketFieldType::Subject,
+            "description" => TicketFieldType::Description,
+            "status" => TicketFieldType::Status,
+            "custom_status" => TicketFieldType::CustomStatus,
+            "tickettype" => TicketFieldType::TicketType,
+            "priority" | "basic_priority" => TicketFieldType::Priority,
+            "group" => TicketFieldType::Group,
+            "assignee" => TicketFieldType::Assignee,
+            "text" => TicketFieldType::Text,
+            "textarea" => TicketFieldType::Textarea,
+            "checkbox" => TicketFieldType::Checkbox,
+            "date" => TicketFieldType::Date,
+            "integer" => TicketFieldType::Integer,
+            "decimal" => TicketFieldType::Decimal,
+            "regexp" => TicketFieldType::Regexp,
+            "partialcreditcard" => TicketFieldType::PartialCreditCard,
+            "multiselect" => TicketFieldType::Multiselect,
+            "tagger" => TicketFieldType::Tagger,
+            "lookup" => TicketFieldType::Lookup,
+            _ => TicketFieldType::Other(value),
+        }
+    }
+}
+
+impl From<TicketFieldType> for String {
+    fn from(value: TicketFieldType) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    #[serde(rename = "type")]
+    pub field_type: TicketFieldType,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removable: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapsed_for_agents: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regexp_for_validation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_in_portal: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_title_in_portal: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editable_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_in_portal: Option<bool>,
+
+    /// Tag added to tickets when a checkbox field is checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_type_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_field_options: Option<Vec<CustomFieldOption>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_field_options: Option<Vec<serde_json::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_target_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_filter: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl TicketField {
+    /// Interpret a raw value of this field
+    pub fn parse_value(&self, value: &serde_json::Value) -> Result<CustomFieldValue> {
+        CustomFieldValue::parse(
+            self.field_type.kind(),
+            &self.title,
+            self.custom_field_options.as_deref().unwrap_or_default(),
+            value,
+        )
+    }
+
+    /// Find a dropdown or multiselect option by its tag value
+    pub fn option_by_value(&self, value: &str) -> Option<&CustomFieldOption> {
+        self.custom_field_options
+            .as_ref()?
+            .iter()
+            .find(|option| option.value == value)
+    }
+
+    /// Find a dropdown or multiselect option by its display name
+    pub fn option_by_name(&self, name: &str) -> Option<&CustomFieldOption> {
+        self.custom_field_options
+            .as_ref()?
+            .iter()
+            .find(|option| option.name == name)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFieldResponse {
+    pub ticket_field: TicketField,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFieldsResponse {
+    pub ticket_fields: Vec<TicketField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFieldCreateRequest {
+    pub ticket_field: TicketFieldCreate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFieldCreate {
+    #[serde(rename = "type")]
+    pub field_type: TicketFieldType,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regexp_for_validation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_in_portal: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editable_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_field_options: Option<Vec<CreateCustomFieldOption>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_target_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_filter: Option<serde_json::Value>,
+}
+
+impl TicketFieldCreate {
+    pub fn new(field_type: TicketFieldType, title: impl Into<String>) -> Self {
+        Self {
+            field_type,
+            title: title.into(),
+            description: None,
+            agent_description: None,
+            position: None,
+            active: None,
+            required: None,
+            regexp_for_validation: None,
+            title_in_portal: None,
+            visible_in_portal: None,
+            editable_in_portal: None,
+            required_in_portal: None,
+            tag: None,
+            custom_field_options: None,
+            relationship_target_type: None,
+            relationship_filter: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn agent_description(mut self, agent_description: impl Into<String>) -> Self {
+        self.agent_description = Some(agent_description.into());
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn regexp_for_validation(mut self, regexp: impl Into<String>) -> Self {
+        self.regexp_for_validation = Some(regexp.into());
+        self
+    }
+
+    /// Show the field to end users in the help center, optionally editable and required
+    pub fn portal(mut self, title: impl Into<String>, editable: bool, required: bool) -> Self {
+        self.title_in_portal = Some(title.into());
+        self.visible_in_portal = Some(true);
+        self.editable_in_portal = Some(editable);
+        self.required_in_portal = Some(required);
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn option(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_field_options
+            .get_or_insert_with(Vec::new)
+            .push(CreateCustomFieldOption {
+                name: name.into(),
+                value: value.into(),
+                position: None,
+                default: None,
+            });
+        self
+    }
+
+    pub fn lookup_target(
+        mut self,
+        target_type: impl Into<String>,
+        filter: Option<serde_json::Value>,
+    ) -> Self {
+        self.relationship_target_type = Some(target_type.into());
+        self.relationship_filter = filter;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFieldUpdateRequest {
+    pub ticket_field: TicketFieldUpdate,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketFieldUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regexp_for_validation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_in_portal: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editable_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_in_portal: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Replaces the complete option list; omitted options are removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_field_options: Option<Vec<CustomFieldOption>>,
+}
+
+impl TicketFieldUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn custom_field_options(mut self, options: Vec<CustomFieldOption>) -> Self {
+        self.custom_field_options = Some(options);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldOptionRequest {
+    pub custom_field_option: CustomFieldOption,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldOptionResponse {
+    pub custom_field_option: CustomFieldOption,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldOptionsResponse {
+    pub custom_field_options: Vec<CustomFieldOption>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+// Ticket forms
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketForm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_display_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_user_visible: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_all_brands: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restricted_brand_ids: Option<Vec<u64>>,
+
+    #[serde(default)]
+    pub ticket_field_ids: Vec<u64>,
+
+    #[serde(default)]
+    pub agent_conditions: Vec<TicketFormCondition>,
+
+    #[serde(default)]
+    pub end_user_conditions: Vec<TicketFormCondition>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Show `child_fields` only when `parent_field_id` has `value`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFormCondition {
+    pub parent_field_id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_field_type: Option<String>,
+
+    pub value: serde_json::Value,
+
+    #[serde(default)]
+    pub child_fields: Vec<ConditionChildField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionChildField {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_required: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_on_statuses: Option<RequiredOnStatuses>,
+}
+
+/// Agent conditions only: which statuses make the child field required
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequiredOnStatuses {
+    /// One of "NO_STATUSES", "ALL_STATUSES" or "SOME_STATUSES"
+    #[serde(rename = "type")]
+    pub requirement_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<Vec<String>>,
+}
+
+impl TicketFormCondition {
+    pub fn new(parent_field_id: u64, value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            parent_field_id,
+            parent_field_type: None,
+            value: value.into(),
+            child_fields: Vec::new(),
+        }
+    }
+
+    pub fn child_field(mut self, field_id: u64, is_required: bool) -> Self {
+        self.child_fields.push(ConditionChildField {
+            id: field_id,
+            is_required: Some(is_required),
+            required_on_statuses: None,
+        });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFormResponse {
+    pub ticket_form: TicketForm,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFormsResponse {
+    pub ticket_forms: Vec<TicketForm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFormRequest {
+    pub ticket_form: TicketFormUpsert,
+}
+
+/// Body for creating or updating a ticket form; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketFormUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_user_visible: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_all_brands: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restricted_brand_ids: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_field_ids: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_conditions: Option<Vec<TicketFormCondition>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_user_conditions: Option<Vec<TicketFormCondition>>,
+}
+
+impl TicketFormUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn default_form(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    pub fn end_user_visible(mut self, visible: bool) -> Self {
+        self.end_user_visible = Some(visible);
+        self
+    }
+
+    pub fn restricted_brand_ids(mut self, brand_ids: Vec<u64>) -> Self {
+        self.in_all_brands = Some(false);
+        self.restricted_brand_ids = Some(brand_ids);
+        self
+    }
+
+    pub fn ticket_field_ids(mut self, field_ids: Vec<u64>) -> Self {
+        self.ticket_field_ids = Some(field_ids);
+        self
+    }
+
+    pub fn agent_conditions(mut self, conditions: Vec<TicketFormCondition>) -> Self {
+        self.agent_conditions = Some(conditions);
+        self
+    }
+
+    pub fn end_user_conditions(mut self, conditions: Vec<TicketFormCondition>) -> Self {
+        self.end_user_conditions = Some(conditions);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketFormReorderRequest {
+    pub ticket_form_ids: Vec<u64>,
+}
+
+/// Resolves a ticket's `custom_fields` against the account's ticket field definitions.
+/// Lookups and parsing go through a ticket-only [`CustomFieldRegistry`].
+#[derive(Debug, Clone)]
+pub struct TicketFieldResolver {
+    fields: HashMap<u64, TicketField>,
+    registry: CustomFieldRegistry,
+}
+
+impl TicketFieldResolver {
+    pub fn new(fields: Vec<TicketField>) -> Self {
+        let registry = CustomFieldRegistry::new(&fields, &[], &[]);
+        Self {
+            fields: fields
+                .into_iter()
+                .filter_map(|field| field.id.map(|id| (id, field)))
+                .collect(),
+            registry,
+        }
+    }
+
+    pub fn field_by_id(&self, field_id: u64) -> Option<&TicketField> {
+        self.fields.get(&field_id)
+    }
+
+    /// Find a custom field by its title or raw (dynamic content) title, ignoring case
+    pub fn field(&self, title: &str) -> Option<&TicketField> {
+        let field = self.registry.field(FieldScope::Ticket, title).ok()?;
+        self.fields.get(&field.id)
+    }
+
+    /// Typed value of the field named `title` on `ticket`, `None` if the ticket does not
+    /// carry that field
+    pub fn value(&self, ticket: &Ticket, title: &str) -> Result<Option<CustomFieldValue>> {
+        self.registry.ticket_value(ticket, title)
+    }
+
+    /// Every known custom field value on `ticket`, keyed by field title. Fields without a
+    /// definition are skipped; values that cannot be interpreted are reported per field.
+    pub fn values(&self, ticket: &Ticket) -> ResolvedFieldValues {
+        self.registry.ticket_values(ticket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    fn fields() -> Vec<TicketField> {
+        serde_json::from_value(json!([
+            { "id": 1, "type": "tagger", "title": "Product", "custom_field_options": [
+                { "id": 10, "name": "Printer", "value": "product_printer" },
+                { "id": 11, "name": "Scanner", "value": "product_scanner" }
+            ]},
+            { "id": 2, "type": "date", "title": "Renewal date" },
+            { "id": 3, "type": "decimal", "title": "Refund amount" },
+            { "id": 4, "type": "checkbox", "title": "VIP", "tag": "vip" },
+            { "id": 5, "type": "lookup", "title": "Asset", "relationship_target_type": "zen:custom_object:asset" },
+            { "id": 6, "type": "basic_priority", "title": "Priority" }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_field_type_round_trip() {
+        let fields = fields();
+        assert_eq!(fields[0].field_type, TicketFieldType::Tagger);
+        assert_eq!(fields[5].field_type, TicketFieldType::Priority);
+        assert!(fields[5].field_type.is_system());
+
+        let other: TicketFieldType = serde_json::from_value(json!("brand_new_type")).unwrap();
+        assert_eq!(
+            serde_json::to_value(other).unwrap(),
+            json!("brand_new_type")
+        );
+    }
+
+    #[test]
+    fn test_resolver_values() {
+        let resolver = TicketFieldResolver::new(fields());
+        let ticket: Ticket = serde_json::from_value(json!({
+            "id": 100,
+            "subject": "Refund",
+            "custom_fields": [
+                { "id": 1, "value": "product_scanner" },
+                { "id": 2, "value": "2024-02-29" },
+                { "id": 3, "value": "12.50" },
+                { "id": 4, "value": true },
+                { "id": 5, "value": "01GDXYD7ZTWYP542BA8MDDTE36" },
+                { "id": 99, "value": "no definition" }
+            ]
+        }))
+        .unwrap();
+
+        match resolver.value(&ticket, "product").unwrap() {
+            Some(CustomFieldValue::Dropdown(option)) => assert_eq!(option.name, "Scanner"),
+            other => panic!("unexpected value {:?}", other),
+        }
+        assert_eq!(
+            resolver.value(&ticket, "Renewal date").unwrap(),
+            Some(CustomFieldValue::Date(
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+            ))
+        );
+        assert_eq!(
+            resolver.value(&ticket, "Refund amount").unwrap(),
+            Some(CustomFieldValue::Decimal(12.5))
+        );
+        assert!(resolver.value(&ticket, "Nonexistent").is_err());
+
+        let values = resolver.values(&ticket).values;
+        assert_eq!(values.len(), 5);
+        assert_eq!(values["VIP"], CustomFieldValue::Checkbox(true));
+        assert_eq!(
+            values["Asset"].to_json(),
+            json!("01GDXYD7ZTWYP542BA8MDDTE36")
+        );
+    }
+
+    #[test]
+    fn test_resolver_values_collects_field_errors() {
+        let resolver = TicketFieldResolver::new(fields());
+        let ticket: Ticket = serde_json::from_value(json!({
+            "id": 100,
+            "subject": "Refund",
+            "custom_fields": [
+                { "id": 1, "value": "product_fax" },
+                { "id": 3, "value": "12.50" }
+            ]
+        }))
+        .unwrap();
+
+        let resolved = resolver.values(&ticket);
+        assert_eq!(
+            resolved.values["Refund amount"],
+            CustomFieldValue::Decimal(12.5)
+        );
+        assert_eq!(resolved.errors.len(), 1);
+        assert_eq!(resolved.errors[0].0, "Product");
+    }
+
+    #[test]
+    fn test_unknown_option_is_error() {
+        let fields = fields();
+        let result = fields[0].parse_value(&json!("product_fax"));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::ticket::{CustomField, TicketPriority, TicketStatus, TicketType};
+
+/// A historical ticket for the import API. Unlike a normal create, the original
+/// timestamps are kept and triggers do not run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketImport {
+    pub subject: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<TicketImportComment>,
+
+    pub requester_id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitter_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TicketStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TicketPriority>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub ticket_type: Option<TicketType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solved_at: Option<DateTime<Utc>>,
+}
+
+/// A comment on an imported ticket, keeping its original author and time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketImportComment {
+    pub author_id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploads: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketImportRequest {
+    pub ticket: TicketImport,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketImportManyRequest {
+    pub tickets: Vec<TicketImport>,
+}
+
+impl TicketImport {
+    pub fn new(subject: impl Into<String>, requester_id: u64) -> Self {
+        Self {
+            subject: subject.into(),
+            description: None,
+            comments: Vec::new(),
+            requester_id,
+            submitter_id: None,
+            assignee_id: None,
+            group_id: None,
+            organization_id: None,
+            status: None,
+            priority: None,
+            ticket_type: None,
+            external_id: None,
+            tags: None,
+            custom_fields: None,
+            created_at: None,
+            updated_at: None,
+            solved_at: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn comment(mut self, comment: TicketImportComment) -> Self {
+        self.comments.push(comment);
+        self
+    }
+
+    pub fn submitter_id(mut self, submitter_id: u64) -> Self {
+        self.submitter_id = Some(submitter_id);
+        self
+    }
+
+    pub fn assignee_id(mut self, assignee_id: u64) -> Self {
+        self.assignee_id = Some(assignee_id);
+        self
+    }
+
+    pub fn group_id(mut self, group_id: u64) -> Self {
+        self.group_id = Some(group_id);
+        self
+    }
+
+    pub fn organization_id(mut self, organization_id: u64) -> Self {
+        self.organization_id = Some(organization_id);
+        self
+    }
+
+    pub fn status(mut self, status: TicketStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn priority(mut self, priority: TicketPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn ticket_type(mut self, ticket_type: TicketType) -> Self {
+        self.ticket_type = Some(ticket_type);
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    pub fn custom_fields(mut self, custom_fields: Vec<CustomField>) -> Self {
+        self.custom_fields = Some(custom_fields);
+        self
+    }
+
+    pub fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn updated_at(mut self, updated_at: DateTime<Utc>) -> Self {
+        self.updated_at = Some(updated_at);
+        self
+    }
+
+    pub fn solved_at(mut self, solved_at: DateTime<Utc>) -> Self {
+        self.solved_at = Some(solved_at);
+        self
+    }
+}
+
+impl TicketImportComment {
+    pub fn new(author_id: u64, value: impl Into<String>) -> Self {
+        Self {
+            author_id,
+            value: Some(value.into()),
+            html_body: None,
+            public: None,
+            created_at: None,
+            uploads: None,
+        }
+    }
+
+    pub fn html(author_id: u64, html_body: impl Into<String>) -> Self {
+        Self {
+            author_id,
+            value: None,
+            html_body: Some(html_body.into()),
+            public: None,
+            created_at: None,
+            uploads: None,
+        }
+    }
+
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    pub fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn uploads(mut self, tokens: Vec<String>) -> Self {
+        self.uploads = Some(tokens);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn test_ticket_import_serialization() {
+        let created_at = Utc.with_ymd_and_hms(2019, 3, 4, 9, 30, 0).unwrap();
+        let import = TicketImport::new("Legacy ticket", 42)
+            .status(TicketStatus::Solved)
+            .created_at(created_at)
+            .solved_at(Utc.with_ymd_and_hms(2019, 3, 5, 17, 0, 0).unwrap())
+            .comment(TicketImportComment::new(42, "The printer is broken").created_at(created_at))
+            .comment(TicketImportComment::new(7, "Replaced the toner").public(true));
+
+        let value = serde_json::to_value(TicketImportRequest { ticket: import }).unwrap();
+        assert_eq!(value["ticket"]["created_at"], json!("2019-03-04T09:30:00Z"));
+        assert_eq!(value["ticket"]["solved_at"], json!("2019-03-05T17:00:00Z"));
+        assert_eq!(value["ticket"]["comments"][0]["author_id"], json!(42));
+        assert_eq!(
+            value["ticket"]["comments"][0]["created_at"],
+            json!("2019-03-04T09:30:00Z")
+        );
+        assert!(value["ticket"]["comments"][1].get("created_at").is_none());
+    }
+}
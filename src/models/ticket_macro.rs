@@ -0,0 +1,390 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::errors::{Result, ZendeskError};
+use crate::models::ticket::{
+    CustomField, TicketChanges, TicketCommentCreate, TicketPriority, TicketStatus, TicketType,
+};
+
+/// Writable ticket properties a macro result may carry outside the typed fields
+const FORWARDED_TICKET_KEYS: &[&str] = &[
+    "brand_id",
+    "collaborator_ids",
+    "due_at",
+    "email_cc_ids",
+    "external_id",
+    "follower_ids",
+    "organization_id",
+    "requester_id",
+    "ticket_form_id",
+];
+
+/// A Zendesk macro. The module is not called `macro` because that is a reserved word.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Macro {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction: Option<MacroRestriction>,
+
+    #[serde(default)]
+    pub actions: Vec<MacroAction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Macro {
+    /// The writable parts of this macro, e.g. to copy it or redeploy it elsewhere
+    pub fn to_upsert(&self) -> MacroUpsert {
+        MacroUpsert {
+            title: Some(self.title.clone()),
+            description: self.description.clone(),
+            active: self.active,
+            restriction: self.restriction.clone(),
+            actions: Some(self.actions.clone()),
+        }
+    }
+}
+
+/// Limits who can see a macro: a single user or one or more groups
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroRestriction {
+    /// "User" or "Group"
+    #[serde(rename = "type")]
+    pub restriction_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<u64>>,
+}
+
+impl MacroRestriction {
+    pub fn user(user_id: u64) -> Self {
+        Self {
+            restriction_type: "User".to_string(),
+            id: Some(user_id),
+            ids: None,
+        }
+    }
+
+    pub fn groups(group_ids: Vec<u64>) -> Self {
+        Self {
+            restriction_type: "Group".to_string(),
+            id: None,
+            ids: Some(group_ids),
+        }
+    }
+}
+
+/// One change a macro makes, e.g. `{ "field": "status", "value": "solved" }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacroAction {
+    pub field: String,
+    pub value: serde_json::Value,
+}
+
+impl MacroAction {
+    pub fn new(field: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroResponse {
+    #[serde(rename = "macro")]
+    pub ticket_macro: Macro,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacrosResponse {
+    pub macros: Vec<Macro>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroRequest {
+    #[serde(rename = "macro")]
+    pub ticket_macro: MacroUpsert,
+}
+
+/// Body for creating or updating a macro; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MacroUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction: Option<MacroRestriction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<MacroAction>>,
+}
+
+impl MacroUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn restriction(mut self, restriction: MacroRestriction) -> Self {
+        self.restriction = Some(restriction);
+        self
+    }
+
+    pub fn action(mut self, action: MacroAction) -> Self {
+        self.actions.get_or_insert_with(Vec::new).push(action);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroApplyResponse {
+    pub result: MacroApplyResult,
+}
+
+/// What a macro would do to a ticket. Nothing is saved until the changes are sent back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroApplyResult {
+    pub ticket: MacroTicketChanges,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<MacroComment>,
+}
+
+/// The would-be ticket after applying a macro. Only the fields the macro touches are
+/// guaranteed to be present when no ticket was given.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroTicketChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TicketStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TicketPriority>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub ticket_type: Option<TicketType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroComment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoped_body: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+}
+
+impl MacroApplyResult {
+    /// The update that saves this result to the ticket. Fails rather than dropping a
+    /// change that cannot be sent.
+    pub fn to_ticket_changes(&self) -> Result<TicketChanges> {
+        let ticket = &self.ticket;
+        let custom_fields = match (&ticket.custom_fields, ticket.extra.get("fields")) {
+            (Some(custom_fields), _) => Some(custom_fields.clone()),
+            // `fields` is an alias of `custom_fields`, sometimes given as a single object
+            (None, Some(fields)) => Some(parse_fields(fields)?),
+            (None, None) => None,
+        };
+        let comment = match &self.comment {
+            Some(comment) => comment.to_comment_create()?,
+            None => None,
+        };
+
+        Ok(TicketChanges {
+            comment,
+            subject: ticket.subject.clone(),
+            status: ticket.status.clone(),
+            priority: ticket.priority,
+            ticket_type: ticket.ticket_type.clone(),
+            assignee_id: ticket.assignee_id,
+            group_id: ticket.group_id,
+            tags: ticket.tags.clone(),
+            custom_fields,
+            extra: ticket
+                .extra
+                .iter()
+                .filter(|(key, _)| FORWARDED_TICKET_KEYS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            ..TicketChanges::default()
+        })
+    }
+}
+
+fn parse_fields(fields: &serde_json::Value) -> Result<Vec<CustomField>> {
+    let parsed = if fields.is_array() {
+        serde_json::from_value(fields.clone())
+    } else {
+        serde_json::from_value(fields.clone()).map(|field| vec![field])
+    };
+    parsed.map_err(|e| {
+        ZendeskError::UnexpectedResponse(format!("Unreadable fields in macro result: {}", e))
+    })
+}
+
+impl MacroComment {
+    /// The comment to post, `None` if the macro produced no text. Locale-specific
+    /// `scoped_body` text cannot be posted on its own, so a comment with nothing else is
+    /// an error.
+    pub fn to_comment_create(&self) -> Result<Option<TicketCommentCreate>> {
+        let not_blank = |text: &&String| !text.trim().is_empty();
+        let body = self.body.as_ref().filter(not_blank);
+        let html_body = self.html_body.as_ref().filter(not_blank);
+        if body.is_none() && html_body.is_none() {
+            if self.scoped_body.is_some() {
+                return Err(ZendeskError::validation(
+                    "Macro comment only has a scoped_body; apply it to a ticket to resolve it",
+                ));
+            }
+            return Ok(None);
+        }
+
+        Ok(Some(TicketCommentCreate {
+            body: body.cloned().unwrap_or_default(),
+            html_body: html_body.cloned(),
+            public: self.public,
+            author_id: None,
+            uploads: None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_result_deserialize() {
+        let response: MacroApplyResponse = serde_json::from_value(json!({
+            "result": {
+                "ticket": {
+                    "id": 35436,
+                    "status": "solved",
+                    "assignee_id": 235323,
+                    "group_id": 98738,
+                    "tags": ["refund", "billing"],
+                    "fields": { "id": 27642, "value": "745" }
+                },
+                "comment": {
+                    "body": "We have processed your refund.",
+                    "html_body": "<p>We have processed your refund.</p>",
+                    "public": true
+                }
+            }
+        }))
+        .unwrap();
+
+        let result = response.result;
+        assert!(matches!(result.ticket.status, Some(TicketStatus::Solved)));
+        assert!(result.ticket.extra.contains_key("fields"));
+        let changes = result.to_ticket_changes().unwrap();
+        assert!(matches!(changes.status, Some(TicketStatus::Solved)));
+        assert_eq!(changes.custom_fields.as_ref().unwrap()[0].id, 27642);
+        let comment = changes.comment.unwrap();
+        assert_eq!(comment.body, "We have processed your refund.");
+        assert_eq!(
+            comment.html_body.as_deref(),
+            Some("<p>We have processed your refund.</p>")
+        );
+        assert_eq!(comment.public, Some(true));
+    }
+
+    #[test]
+    fn test_scoped_only_comment_is_rejected() {
+        let comment: MacroComment = serde_json::from_value(json!({
+            "scoped_body": [["en-US", "Hello"], ["fr", "Bonjour"]]
+        }))
+        .unwrap();
+        assert!(comment.to_comment_create().is_err());
+
+        let comment: MacroComment = serde_json::from_value(json!({ "body": "  " })).unwrap();
+        assert!(comment.to_comment_create().unwrap().is_none());
+    }
+}
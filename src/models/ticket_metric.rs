@@ -0,0 +1,407 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A duration measured both in calendar time and within business hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetricMinutes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calendar: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketMetric {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub ticket_id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_stations: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_stations: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reopens: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replies: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_time_in_minutes: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_time_in_seconds: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_resolution_time_in_minutes: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_resolution_time_in_minutes: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_wait_time_in_minutes: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requester_wait_time_in_minutes: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_hold_time_in_minutes: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requester_updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initially_assigned_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solved_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_comment_added_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketMetricResponse {
+    pub ticket_metric: TicketMetric,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketMetricsResponse {
+    pub ticket_metrics: Vec<TicketMetric>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+/// The metric a ticket metric event belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TicketMetricKind {
+    AgentWorkTime,
+    PausableUpdateTime,
+    PeriodicUpdateTime,
+    ReplyTime,
+    RequesterWaitTime,
+    ResolutionTime,
+    GroupOwnershipTime,
+    Other(String),
+}
+
+impl TicketMetricKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TicketMetricKind::AgentWorkTime => "agent_work_time",
+            TicketMetricKind::PausableUpdateTime => "pausable_update_time",
+            TicketMetricKind::PeriodicUpdateTime => "periodic_update_time",
+            TicketMetricKind::ReplyTime => "reply_time",
+            TicketMetricKind::RequesterWaitTime => "requester_wait_time",
+            TicketMetricKind::ResolutionTime => "resolution_time",
+            TicketMetricKind::GroupOwnershipTime => "group_ownership_time",
+            TicketMetricKind::Other(other) => other,
+        }
+    }
+}
+
+impl From<String> for TicketMetricKind {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "agent_work_time" => TicketMetricKind::AgentWorkTime,
+            "pausable_update_time" => TicketMetricKind::PausableUpdateTime,
+            "periodic_update_time" => TicketMetricKind::PeriodicUpdateTime,
+            "reply_time" => TicketMetricKind::ReplyTime,
+            "requester_wait_time" => TicketMetricKind::RequesterWaitTime,
+            "resolution_time" => TicketMetricKind::ResolutionTime,
+            "group_ownership_time" => TicketMetricKind::GroupOwnershipTime,
+            _ => TicketMetricKind::Other(value),
+        }
+    }
+}
+
+impl From<TicketMetricKind> for String {
+    fn from(value: TicketMetricKind) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketMetricEventType {
+    Activate,
+    Pause,
+    Fulfill,
+    ApplySla,
+    ApplyGroupSla,
+    Breach,
+    UpdateStatus,
+    Measure,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPolicyRef {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// SLA details attached to `apply_sla` events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaEventDetails {
+    /// Target in minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_in_seconds: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_hours: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<SlaPolicyRef>,
+}
+
+/// An entry from `incremental/ticket_metric_events`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketMetricEvent {
+    pub id: u64,
+    pub ticket_id: u64,
+    pub metric: TicketMetricKind,
+
+    /// Distinguishes repeated occurrences of the same metric on one ticket
+    pub instance_id: u64,
+
+    #[serde(rename = "type")]
+    pub event_type: TicketMetricEventType,
+
+    pub time: DateTime<Utc>,
+
+    /// Present on `apply_sla` and `apply_group_sla` events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sla: Option<SlaEventDetails>,
+
+    /// Present on `update_status` and `measure` events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<MetricMinutes>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlaTargetStatus {
+    Active,
+    Paused,
+    Fulfilled,
+}
+
+/// The state of one SLA target on a ticket, reconstructed from its metric events
+#[derive(Debug, Clone)]
+pub struct SlaTarget {
+    pub ticket_id: u64,
+    pub metric: TicketMetricKind,
+    pub instance_id: u64,
+    pub policy: Option<SlaPolicyRef>,
+    pub target_minutes: Option<i64>,
+    pub business_hours: bool,
+    pub status: SlaTargetStatus,
+    pub breached: bool,
+    pub breached_at: Option<DateTime<Utc>>,
+    pub fulfilled_at: Option<DateTime<Utc>>,
+}
+
+impl SlaTarget {
+    /// Still running (or paused) and not yet breached
+    pub fn is_active(&self) -> bool {
+        self.status != SlaTargetStatus::Fulfilled && !self.breached
+    }
+}
+
+/// Compute the SLA targets of each ticket from its metric events, as they stood at `as_of`.
+/// Only metric instances with an `apply_sla` event are included; events may be passed in
+/// any order.
+///
+/// Zendesk records a `breach` event ahead of time with the moment the target will be
+/// missed, so a breach only counts once its time has passed and the target was not
+/// fulfilled first.
+pub fn sla_targets_by_ticket(
+    events: &[TicketMetricEvent],
+    as_of: DateTime<Utc>,
+) -> HashMap<u64, Vec<SlaTarget>> {
+    let mut sorted: Vec<&TicketMetricEvent> = events
+        .iter()
+        .filter(|event| event.deleted != Some(true))
+        .collect();
+    sorted.sort_by_key(|event| (event.time, event.id));
+
+    let mut targets: HashMap<(u64, TicketMetricKind, u64), SlaTarget> = HashMap::new();
+    for event in sorted {
+        let key = (event.ticket_id, event.metric.clone(), event.instance_id);
+
+        if event.event_type == TicketMetricEventType::ApplySla {
+            let sla = event.sla.as_ref();
+            let target = targets.entry(key).or_insert_with(|| SlaTarget {
+                ticket_id: event.ticket_id,
+                metric: event.metric.clone(),
+                instance_id: event.instance_id,
+                policy: None,
+                target_minutes: None,
+                business_hours: false,
+                status: SlaTargetStatus::Active,
+                breached: false,
+                breached_at: None,
+                fulfilled_at: None,
+            });
+            target.policy = sla.and_then(|sla| sla.policy.clone());
+            target.target_minutes = sla.and_then(|sla| sla.target);
+            target.business_hours = sla.and_then(|sla| sla.business_hours).unwrap_or(false);
+            continue;
+        }
+
+        let Some(target) = targets.get_mut(&key) else {
+            continue;
+        };
+        match event.event_type {
+            TicketMetricEventType::Activate => target.status = SlaTargetStatus::Active,
+            TicketMetricEventType::Pause => target.status = SlaTargetStatus::Paused,
+            TicketMetricEventType::Fulfill => {
+                target.status = SlaTargetStatus::Fulfilled;
+                target.fulfilled_at = Some(event.time);
+            }
+            TicketMetricEventType::Breach
+                if event.time <= as_of && target.status != SlaTargetStatus::Fulfilled =>
+            {
+                target.breached = true;
+                target.breached_at = Some(event.time);
+            }
+            _ => {}
+        }
+    }
+
+    let mut by_ticket: HashMap<u64, Vec<SlaTarget>> = HashMap::new();
+    for target in targets.into_values() {
+        by_ticket.entry(target.ticket_id).or_default().push(target);
+    }
+    for ticket_targets in by_ticket.values_mut() {
+        ticket_targets
+            .sort_by_key(|target| (target.metric.as_str().to_string(), target.instance_id));
+    }
+    by_ticket
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ticket_metric_deserialize() {
+        let metric: TicketMetric = serde_json::from_value(json!({
+            "id": 33,
+            "ticket_id": 4343,
+            "reopens": 55,
+            "replies": 322,
+            "reply_time_in_minutes": { "calendar": 2391, "business": 737 },
+            "full_resolution_time_in_minutes": { "calendar": 2391, "business": 737 },
+            "solved_at": "2011-05-09T10:38:52Z",
+            "created_at": "2009-07-20T22:55:29Z"
+        }))
+        .unwrap();
+
+        assert_eq!(metric.reply_time_in_minutes.unwrap().business, Some(737));
+        assert!(metric.solved_at.is_some());
+    }
+
+    #[test]
+    fn test_sla_targets_from_events() {
+        let events: Vec<TicketMetricEvent> = serde_json::from_value(json!([
+            { "id": 4, "ticket_id": 155, "metric": "reply_time", "instance_id": 1, "type": "breach", "time": "2020-10-26T13:00:00Z" },
+            { "id": 1, "ticket_id": 155, "metric": "reply_time", "instance_id": 1, "type": "activate", "time": "2020-10-26T12:53:12Z" },
+            { "id": 2, "ticket_id": 155, "metric": "reply_time", "instance_id": 1, "type": "apply_sla", "time": "2020-10-26T12:53:12Z",
+              "sla": { "target": 5, "business_hours": false, "policy": { "id": 360000149852_u64, "title": "Urgent" } } },
+            { "id": 5, "ticket_id": 155, "metric": "resolution_time", "instance_id": 1, "type": "apply_sla", "time": "2020-10-26T12:53:12Z",
+              "sla": { "target": 480, "business_hours": true } },
+            { "id": 6, "ticket_id": 155, "metric": "agent_work_time", "instance_id": 1, "type": "activate", "time": "2020-10-26T12:53:12Z" },
+            { "id": 7, "ticket_id": 200, "metric": "reply_time", "instance_id": 1, "type": "apply_sla", "time": "2020-10-26T12:00:00Z",
+              "sla": { "target": 60, "business_hours": false } },
+            { "id": 8, "ticket_id": 200, "metric": "reply_time", "instance_id": 1, "type": "fulfill", "time": "2020-10-26T12:30:00Z" }
+        ]))
+        .unwrap();
+
+        let as_of = "2020-10-26T14:00:00Z".parse().unwrap();
+        let targets = sla_targets_by_ticket(&events, as_of);
+        let ticket_155 = &targets[&155];
+        assert_eq!(ticket_155.len(), 2);
+        assert_eq!(ticket_155[0].metric, TicketMetricKind::ReplyTime);
+        assert!(ticket_155[0].breached);
+        assert!(!ticket_155[0].is_active());
+        assert!(ticket_155[1].is_active());
+        assert!(ticket_155[1].business_hours);
+
+        let ticket_200 = &targets[&200];
+        assert_eq!(ticket_200[0].status, SlaTargetStatus::Fulfilled);
+        assert!(!ticket_200[0].breached);
+    }
+
+    #[test]
+    fn test_sla_breach_scheduled_ahead() {
+        let events: Vec<TicketMetricEvent> = serde_json::from_value(json!([
+            { "id": 1, "ticket_id": 1, "metric": "reply_time", "instance_id": 1, "type": "apply_sla", "time": "2020-10-26T12:00:00Z",
+              "sla": { "target": 60, "business_hours": false } },
+            { "id": 2, "ticket_id": 1, "metric": "reply_time", "instance_id": 1, "type": "activate", "time": "2020-10-26T12:00:00Z" },
+            { "id": 3, "ticket_id": 1, "metric": "reply_time", "instance_id": 1, "type": "breach", "time": "2020-10-26T13:00:00Z" },
+            { "id": 4, "ticket_id": 2, "metric": "reply_time", "instance_id": 1, "type": "apply_sla", "time": "2020-10-26T12:00:00Z",
+              "sla": { "target": 60, "business_hours": false } },
+            { "id": 5, "ticket_id": 2, "metric": "reply_time", "instance_id": 1, "type": "breach", "time": "2020-10-26T13:00:00Z" },
+            { "id": 6, "ticket_id": 2, "metric": "reply_time", "instance_id": 1, "type": "fulfill", "time": "2020-10-26T12:40:00Z" }
+        ]))
+        .unwrap();
+
+        // Before the scheduled breach the target is still running
+        let targets = sla_targets_by_ticket(&events, "2020-10-26T12:30:00Z".parse().unwrap());
+        assert!(!targets[&1][0].breached);
+        assert!(targets[&1][0].is_active());
+
+        let targets = sla_targets_by_ticket(&events, "2020-10-26T13:30:00Z".parse().unwrap());
+        assert!(targets[&1][0].breached);
+
+        // Fulfilled before its deadline, so the pending breach never happens
+        assert_eq!(targets[&2][0].status, SlaTargetStatus::Fulfilled);
+        assert!(!targets[&2][0].breached);
+        assert_eq!(targets[&2][0].breached_at, None);
+    }
+}
@@ -0,0 +1,344 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::business_rule::{RuleAction, RuleConditions};
+use crate::query::CursorPagination;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trigger {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    /// Zendesk returns category ids as strings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<String>,
+
+    #[serde(default)]
+    pub conditions: RuleConditions,
+
+    #[serde(default)]
+    pub actions: Vec<RuleAction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Trigger {
+    /// The writable parts of this trigger, e.g. to copy it or redeploy it elsewhere
+    pub fn to_upsert(&self) -> TriggerUpsert {
+        TriggerUpsert {
+            title: Some(self.title.clone()),
+            description: self.description.clone(),
+            active: self.active,
+            position: self.position,
+            category_id: self.category_id.clone(),
+            conditions: Some(self.conditions.clone()),
+            actions: Some(self.actions.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerResponse {
+    pub trigger: Trigger,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggersResponse {
+    pub triggers: Vec<Trigger>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerRequest {
+    pub trigger: TriggerUpsert,
+}
+
+/// Body for creating or updating a trigger; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TriggerUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RuleConditions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<RuleAction>>,
+}
+
+impl TriggerUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn category_id(mut self, category_id: impl Into<String>) -> Self {
+        self.category_id = Some(category_id.into());
+        self
+    }
+
+    pub fn conditions(mut self, conditions: RuleConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    pub fn action(mut self, action: RuleAction) -> Self {
+        self.actions.get_or_insert_with(Vec::new).push(action);
+        self
+    }
+}
+
+/// One entry of `PUT triggers/update_many`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TriggerBatchUpdate {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerBatchUpdateRequest {
+    pub triggers: Vec<TriggerBatchUpdate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerReorderRequest {
+    pub trigger_ids: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoryResponse {
+    pub trigger_category: TriggerCategory,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoriesResponse {
+    pub trigger_categories: Vec<TriggerCategory>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<CursorPagination>,
+}
+
+impl TriggerCategoriesResponse {
+    /// Cursor for the next page, if there is one
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.meta
+            .as_ref()
+            .filter(|meta| meta.has_more)
+            .and_then(|meta| meta.after_cursor.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoryRequest {
+    pub trigger_category: TriggerCategoryUpsert,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TriggerCategoryUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+}
+
+impl TriggerCategoryUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+/// One entry of a trigger category batch job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoryBatchUpdate {
+    pub id: String,
+
+    pub position: u32,
+}
+
+/// Body of `POST trigger_categories/jobs`, which repositions categories and their
+/// triggers in one request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoryJobRequest {
+    pub job: TriggerCategoryJob,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoryJob {
+    pub action: String,
+
+    pub items: TriggerCategoryJobItems,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TriggerCategoryJobItems {
+    #[serde(default)]
+    pub trigger_categories: Vec<TriggerCategoryBatchUpdate>,
+
+    #[serde(default)]
+    pub triggers: Vec<TriggerBatchUpdate>,
+}
+
+impl TriggerCategoryJobRequest {
+    pub fn patch(items: TriggerCategoryJobItems) -> Self {
+        Self {
+            job: TriggerCategoryJob {
+                action: "patch".to_string(),
+                items,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoryJobResponse {
+    pub results: TriggerCategoryJobResults,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerCategoryJobResults {
+    #[serde(default)]
+    pub trigger_categories: Vec<TriggerCategory>,
+
+    #[serde(default)]
+    pub triggers: Vec<Trigger>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::business_rule::RuleCondition;
+    use serde_json::json;
+
+    #[test]
+    fn test_trigger_round_trip() {
+        let source = json!({
+            "id": 25,
+            "title": "Notify requester of received request",
+            "active": true,
+            "position": 8,
+            "category_id": "10026",
+            "conditions": {
+                "all": [{ "field": "update_type", "operator": "is", "value": "Create" }],
+                "any": []
+            },
+            "actions": [
+                { "field": "notification_user", "value": ["requester_id", "Received", "We got it"] }
+            ],
+            "raw_title": "Notify requester of received request",
+            "default": false
+        });
+
+        let trigger: Trigger = serde_json::from_value(source.clone()).unwrap();
+        assert!(matches!(
+            trigger.conditions.all[0],
+            RuleCondition::UpdateType { .. }
+        ));
+        assert_eq!(serde_json::to_value(&trigger).unwrap(), source);
+
+        let upsert = trigger.to_upsert();
+        assert_eq!(upsert.conditions.as_ref(), Some(&trigger.conditions));
+        assert_eq!(upsert.category_id.as_deref(), Some("10026"));
+    }
+}
@@ -1,4 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::job_status::JobStatus;
 
 // Import CustomField from ticket module for consistency
 use crate::models::ticket::CustomField;
@@ -9,11 +13,21 @@ pub struct User {
     pub id: Option<u64>,
 
     pub name: String,
-    pub email: String,
+
+    /// Not every user has an email, e.g. phone-only, social-login and some deleted users
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<UserRole>,
 
+    /// Agent role type on plans with custom roles, e.g. 0 for a custom role, 1 for light agent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_type: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_role_id: Option<u64>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub organization_id: Option<u64>,
 
@@ -29,6 +43,13 @@ pub struct User {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomField>>,
 
+    /// Custom user field values keyed by field key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_fields: Option<HashMap<String, serde_json::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_zone: Option<String>,
 
@@ -41,6 +62,24 @@ pub struct User {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verified: Option<bool>,
 
+    /// Suspended users cannot sign in and their new tickets go to the suspended queue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<bool>,
+
+    /// Whether the user comes from a shared ticket-sharing agreement account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restricted_agent: Option<bool>,
+
+    /// Which tickets a restricted agent can see; `None` for unrestricted users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_restriction: Option<TicketRestriction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_login_at: Option<DateTime<Utc>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
 
@@ -60,6 +99,15 @@ pub enum UserRole {
     Admin,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TicketRestriction {
+    Organization,
+    Groups,
+    Assigned,
+    Requested,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserCreateRequest {
     pub user: UserCreate,
@@ -68,7 +116,10 @@ pub struct UserCreateRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserCreate {
     pub name: String,
-    pub email: String,
+
+    /// Optional when the user is matched or identified by `external_id`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<UserRole>,
@@ -88,6 +139,13 @@ pub struct UserCreate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomField>>,
 
+    /// Custom user field values keyed by field key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_fields: Option<HashMap<String, serde_json::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_zone: Option<String>,
 
@@ -118,6 +176,14 @@ impl User {
     pub fn builder(name: impl Into<String>, email: impl Into<String>) -> UserBuilder {
         UserBuilder::new(name, email)
     }
+
+    /// Start a user identified only by your system's id, e.g. for `create_or_update`
+    pub fn builder_with_external_id(
+        name: impl Into<String>,
+        external_id: impl Into<String>,
+    ) -> UserBuilder {
+        UserBuilder::with_email(name.into(), None).external_id(external_id)
+    }
 }
 
 #[derive(Debug)]
@@ -127,22 +193,33 @@ pub struct UserBuilder {
 
 impl UserBuilder {
     pub fn new(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self::with_email(name.into(), Some(email.into()))
+    }
+
+    fn with_email(name: String, email: Option<String>) -> Self {
         Self {
             user: UserCreate {
-                name: name.into(),
-                email: email.into(),
+                name,
+                email,
                 role: None,
                 organization_id: None,
                 phone: None,
                 notes: None,
                 tags: None,
                 custom_fields: None,
+                user_fields: None,
+                external_id: None,
                 time_zone: None,
                 locale: None,
             },
         }
     }
 
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.user.email = Some(email.into());
+        self
+    }
+
     pub fn role(mut self, role: UserRole) -> Self {
         self.user.role = Some(role);
         self
@@ -173,6 +250,21 @@ impl UserBuilder {
         self
     }
 
+    /// Set a custom user field; see `CustomFieldRegistry::user_field` for checked values
+    pub fn user_field(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.user
+            .user_fields
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value);
+        self
+    }
+
+    /// Your system's id for the user; `create_or_update` matches on it
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.user.external_id = Some(external_id.into());
+        self
+    }
+
     pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
         self.user.time_zone = Some(time_zone.into());
         self
@@ -186,4 +278,307 @@ impl UserBuilder {
     pub fn build(self) -> UserCreateRequest {
         UserCreateRequest { user: self.user }
     }
+
+    /// The user without the request wrapper, for bulk requests
+    pub fn build_user(self) -> UserCreate {
+        self.user
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserCreateManyRequest {
+    pub users: Vec<UserCreate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserUpdateRequest {
+    pub user: UserUpdate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserUpdateManyRequest {
+    pub users: Vec<UserUpdate>,
+}
+
+/// Changes to a user; only set fields are sent. In a list of individual updates each
+/// entry names its user with `id` or `external_id`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<UserRole>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
+    /// Custom user field values keyed by field key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_fields: Option<HashMap<String, serde_json::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<bool>,
+}
+
+impl UserUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An update for the user with this id, for `update_many_users`
+    pub fn for_id(id: u64) -> Self {
+        Self {
+            id: Some(id),
+            ..Self::default()
+        }
+    }
+
+    /// An update for the user with this external id, for `update_many_users`
+    pub fn for_external_id(external_id: impl Into<String>) -> Self {
+        Self {
+            external_id: Some(external_id.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn role(mut self, role: UserRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn organization_id(mut self, organization_id: u64) -> Self {
+        self.organization_id = Some(organization_id);
+        self
+    }
+
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.phone = Some(phone.into());
+        self
+    }
+
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    pub fn user_field(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.user_fields
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value);
+        self
+    }
+
+    pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    pub fn suspended(mut self, suspended: bool) -> Self {
+        self.suspended = Some(suspended);
+        self
+    }
+}
+
+/// A soft-deleted user awaiting permanent deletion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedUser {
+    pub id: u64,
+
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<UserRole>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedUserResponse {
+    pub deleted_user: DeletedUser,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedUsersResponse {
+    pub deleted_users: Vec<DeletedUser>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+/// Response to `DELETE deleted_users/{id}`; the purge may run as a background job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermanentDeletionResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_user: Option<DeletedUser>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_status: Option<JobStatus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_user_update_many_body() {
+        let request = UserUpdateManyRequest {
+            users: vec![
+                UserUpdate::for_id(7).notes("VIP"),
+                UserUpdate::for_external_id("crm-42").organization_id(3),
+            ],
+        };
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            json!({ "users": [
+                { "id": 7, "notes": "VIP" },
+                { "external_id": "crm-42", "organization_id": 3 }
+            ] })
+        );
+
+        let suspend = UserUpdateRequest {
+            user: UserUpdate::new().suspended(true),
+        };
+        assert_eq!(
+            serde_json::to_value(suspend).unwrap(),
+            json!({ "user": { "suspended": true } })
+        );
+
+        let user = User::builder("Ann", "ann@example.com")
+            .external_id("crm-42")
+            .build_user();
+        assert_eq!(user.external_id.as_deref(), Some("crm-42"));
+    }
+
+    #[test]
+    fn test_user_without_email() {
+        let page: UsersResponse = serde_json::from_value(json!({
+            "users": [
+                { "id": 1, "name": "Phone only", "email": null, "phone": "+15550100" },
+                { "id": 2, "name": "Social login" },
+                { "id": 3, "name": "Ann", "email": "ann@example.com" }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(page.users[0].email, None);
+        assert_eq!(page.users[1].email, None);
+        assert_eq!(page.users[2].email.as_deref(), Some("ann@example.com"));
+        assert!(
+            serde_json::to_value(&page.users[0])
+                .unwrap()
+                .get("email")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_user_lifecycle_fields() {
+        let user: User = serde_json::from_value(json!({
+            "id": 12,
+            "name": "Lee",
+            "email": "lee@example.com",
+            "role": "agent",
+            "role_type": 1,
+            "custom_role_id": 360002,
+            "suspended": false,
+            "shared": false,
+            "restricted_agent": true,
+            "ticket_restriction": "groups",
+            "last_login_at": "2024-05-01T08:00:00Z"
+        }))
+        .unwrap();
+        assert_eq!(user.ticket_restriction, Some(TicketRestriction::Groups));
+        assert_eq!(user.role_type, Some(1));
+        assert_eq!(user.restricted_agent, Some(true));
+        assert_eq!(
+            user.last_login_at,
+            Some("2024-05-01T08:00:00Z".parse().unwrap())
+        );
+
+        let unrestricted: User = serde_json::from_value(json!({
+            "id": 13, "name": "Kim", "email": "kim@example.com", "ticket_restriction": null
+        }))
+        .unwrap();
+        assert_eq!(unrestricted.ticket_restriction, None);
+    }
 }
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of a user identity
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum IdentityType {
+    Email,
+    PhoneNumber,
+    Twitter,
+    Facebook,
+    Google,
+    AgentForwarding,
+    AnyChannel,
+    Foreign,
+    Sdk,
+    Other(String),
+}
+
+impl IdentityType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            IdentityType::Email => "email",
+            IdentityType::PhoneNumber => "phone_number",
+            IdentityType::Twitter => "twitter",
+            IdentityType::Facebook => "facebook",
+            IdentityType::Google => "google",
+            IdentityType::AgentForwarding => "agent_forwarding",
+            IdentityType::AnyChannel => "any_channel",
+            IdentityType::Foreign => "foreign",
+            IdentityType::Sdk => "sdk",
+            IdentityType::Other(other) => other,
+        }
+    }
+}
+
+impl From<String> for IdentityType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "email" => IdentityType::Email,
+            "phone_number" => IdentityType::PhoneNumber,
+            "twitter" => IdentityType::Twitter,
+            "facebook" => IdentityType::Facebook,
+            "google" => IdentityType::Google,
+            "agent_forwarding" => IdentityType::AgentForwarding,
+            "any_channel" => IdentityType::AnyChannel,
+            "foreign" => IdentityType::Foreign,
+            "sdk" => IdentityType::Sdk,
+            _ => IdentityType::Other(value),
+        }
+    }
+}
+
+impl From<IdentityType> for String {
+    fn from(value: IdentityType) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+/// One way of reaching a user, e.g. an email address or phone number
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdentity {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+
+    #[serde(rename = "type")]
+    pub identity_type: IdentityType,
+
+    pub value: String,
+
+    #[serde(default)]
+    pub verified: bool,
+
+    #[serde(default)]
+    pub primary: bool,
+
+    /// Email deliverability, e.g. "deliverable" or "undeliverable"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliverable_state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub undeliverable_count: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdentityResponse {
+    pub identity: UserIdentity,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdentitiesResponse {
+    pub identities: Vec<UserIdentity>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdentityCreateRequest {
+    pub identity: UserIdentityCreate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdentityCreate {
+    #[serde(rename = "type")]
+    pub identity_type: IdentityType,
+
+    pub value: String,
+
+    /// Mark the identity verified without sending a verification email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+}
+
+impl UserIdentityCreate {
+    pub fn new(identity_type: IdentityType, value: impl Into<String>) -> Self {
+        Self {
+            identity_type,
+            value: value.into(),
+            verified: None,
+            primary: None,
+        }
+    }
+
+    pub fn email(address: impl Into<String>) -> Self {
+        Self::new(IdentityType::Email, address)
+    }
+
+    pub fn phone_number(number: impl Into<String>) -> Self {
+        Self::new(IdentityType::PhoneNumber, number)
+    }
+
+    pub fn verified(mut self, verified: bool) -> Self {
+        self.verified = Some(verified);
+        self
+    }
+
+    pub fn primary(mut self, primary: bool) -> Self {
+        self.primary = Some(primary);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdentityUpdateRequest {
+    pub identity: UserIdentityUpdate,
+}
+
+/// Changes to an identity; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserIdentityUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_identity_round_trip() {
+        let identities: UserIdentitiesResponse = serde_json::from_value(json!({
+            "identities": [
+                { "id": 1, "user_id": 7, "type": "email", "value": "ann@example.com", "verified": true, "primary": true },
+                { "id": 2, "user_id": 7, "type": "messaging", "value": "ann-chat" }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(identities.identities[0].identity_type, IdentityType::Email);
+        assert!(identities.identities[0].primary);
+        assert_eq!(
+            identities.identities[1].identity_type,
+            IdentityType::Other("messaging".to_string())
+        );
+        assert!(!identities.identities[1].verified);
+
+        let body = serde_json::to_value(UserIdentityCreateRequest {
+            identity: UserIdentityCreate::email("ann@work.example").verified(true),
+        })
+        .unwrap();
+        assert_eq!(
+            body,
+            json!({ "identity": { "type": "email", "value": "ann@work.example", "verified": true } })
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::user::{User, UserRole};
+use crate::models::user_identity::{IdentityType, UserIdentity};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserMergeRequest {
+    pub user: UserMergeTarget,
+}
+
+/// The user that survives a merge
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct UserMergeTarget {
+    pub id: u64,
+}
+
+/// Counts of the records linked to a user, from `users/{id}/related`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserRelated {
+    #[serde(default)]
+    pub assigned_tickets: u64,
+
+    #[serde(default)]
+    pub requested_tickets: u64,
+
+    #[serde(default)]
+    pub ccd_tickets: u64,
+
+    #[serde(default)]
+    pub organization_subscriptions: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry_subscriptions: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forum_subscriptions: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_comments: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserRelatedResponse {
+    pub user_related: UserRelated,
+}
+
+/// A contact detail that more than one user has
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DuplicateKey {
+    /// Lowercased email address
+    Email(String),
+    /// Phone number reduced to its digits
+    Phone(String),
+    ExternalId(String),
+}
+
+impl DuplicateKey {
+    fn email(value: &str) -> Option<Self> {
+        let value = value.trim().to_lowercase();
+        (!value.is_empty()).then_some(DuplicateKey::Email(value))
+    }
+
+    fn phone(value: &str) -> Option<Self> {
+        let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+        // Extensions and short codes are too ambiguous to match on
+        (digits.len() >= 7).then_some(DuplicateKey::Phone(digits))
+    }
+
+    fn external_id(value: &str) -> Option<Self> {
+        let value = value.trim();
+        (!value.is_empty()).then(|| DuplicateKey::ExternalId(value.to_string()))
+    }
+}
+
+impl std::fmt::Display for DuplicateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DuplicateKey::Email(value) => write!(f, "email {}", value),
+            DuplicateKey::Phone(value) => write!(f, "phone {}", value),
+            DuplicateKey::ExternalId(value) => write!(f, "external id {}", value),
+        }
+    }
+}
+
+/// Users that are linked, directly or through each other, by shared contact details
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateUserGroup {
+    /// Sorted ascending
+    pub user_ids: Vec<u64>,
+
+    /// The details shared by at least two users of the group
+    pub shared: Vec<DuplicateKey>,
+}
+
+/// Group users that share an email, phone number or external id. Emails and phone numbers
+/// come from the user record and from `identities`, keyed by user id. Users without an id
+/// are ignored.
+pub fn group_duplicate_users(
+    users: &[User],
+    identities: &HashMap<u64, Vec<UserIdentity>>,
+) -> Vec<DuplicateUserGroup> {
+    let mut owners: BTreeMap<DuplicateKey, Vec<u64>> = BTreeMap::new();
+    for user in users {
+        let Some(id) = user.id else { continue };
+        let identity_keys = identities
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter_map(|identity| match identity.identity_type {
+                IdentityType::Email => DuplicateKey::email(&identity.value),
+                IdentityType::PhoneNumber => DuplicateKey::phone(&identity.value),
+                _ => None,
+            });
+        let keys = user
+            .email
+            .as_deref()
+            .and_then(DuplicateKey::email)
+            .into_iter()
+            .chain(user.phone.as_deref().and_then(DuplicateKey::phone))
+            .chain(
+                user.external_id
+                    .as_deref()
+                    .and_then(DuplicateKey::external_id),
+            )
+            .chain(identity_keys);
+
+        for key in keys {
+            let ids = owners.entry(key).or_default();
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    owners.retain(|_, ids| ids.len() > 1);
+
+    // Union users that share any key
+    let mut parent: HashMap<u64, u64> = HashMap::new();
+    fn root(parent: &mut HashMap<u64, u64>, id: u64) -> u64 {
+        let mut current = id;
+        while let Some(&next) = parent.get(&current) {
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+        parent.insert(id, current);
+        current
+    }
+    for ids in owners.values() {
+        let first = root(&mut parent, ids[0]);
+        for &id in &ids[1..] {
+            let other = root(&mut parent, id);
+            parent.insert(other, first);
+        }
+    }
+
+    let mut groups: BTreeMap<u64, DuplicateUserGroup> = BTreeMap::new();
+    for (key, ids) in owners {
+        let group_root = root(&mut parent, ids[0]);
+        let group = groups
+            .entry(group_root)
+            .or_insert_with(|| DuplicateUserGroup {
+                user_ids: Vec::new(),
+                shared: Vec::new(),
+            });
+        group.user_ids.extend(ids);
+        group.shared.push(key);
+    }
+
+    let mut groups: Vec<DuplicateUserGroup> = groups
+        .into_values()
+        .map(|mut group| {
+            group.user_ids.sort_unstable();
+            group.user_ids.dedup();
+            group
+        })
+        .collect();
+    groups.sort_by_key(|group| group.user_ids[0]);
+    groups
+}
+
+/// Merging `source_user_id` into `target_user_id` and what it would move
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeCandidate {
+    pub source_user_id: u64,
+    pub target_user_id: u64,
+    pub requested_tickets: u64,
+    pub ccd_tickets: u64,
+}
+
+impl MergeCandidate {
+    /// Tickets that would change hands: requested and CC'd tickets of the source user
+    pub fn tickets_to_move(&self) -> u64 {
+        self.requested_tickets + self.ccd_tickets
+    }
+}
+
+/// A duplicate group with the user to keep and the merges that would fold the rest into it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateUserReport {
+    pub group: DuplicateUserGroup,
+
+    /// The end user to keep; `None` when the group has no end user
+    pub target_user_id: Option<u64>,
+
+    pub candidates: Vec<MergeCandidate>,
+
+    /// Agents and admins in the group. Zendesk only merges end users into end users, so
+    /// staff can be neither merged away nor merged into.
+    pub unmergeable_user_ids: Vec<u64>,
+}
+
+impl DuplicateUserReport {
+    /// Pick the end user to keep and list the merges for the other end users of the group.
+    /// The end user with the most requested tickets is kept, then the oldest (lowest id).
+    pub fn build(
+        group: DuplicateUserGroup,
+        users: &[User],
+        related: &HashMap<u64, UserRelated>,
+    ) -> Self {
+        let is_end_user = |id: u64| {
+            users
+                .iter()
+                .find(|user| user.id == Some(id))
+                .is_none_or(|user| matches!(user.role, None | Some(UserRole::EndUser)))
+        };
+        let requested = |id: u64| related.get(&id).map_or(0, |r| r.requested_tickets);
+
+        let (end_users, unmergeable_user_ids): (Vec<u64>, Vec<u64>) =
+            group.user_ids.iter().partition(|&&id| is_end_user(id));
+        let target_user_id = end_users
+            .iter()
+            .copied()
+            .max_by_key(|&id| (requested(id), std::cmp::Reverse(id)));
+
+        let candidates = target_user_id
+            .map(|target_user_id| {
+                end_users
+                    .iter()
+                    .filter(|&&id| id != target_user_id)
+                    .map(|&id| {
+                        let counts = related.get(&id).cloned().unwrap_or_default();
+                        MergeCandidate {
+                            source_user_id: id,
+                            target_user_id,
+                            requested_tickets: counts.requested_tickets,
+                            ccd_tickets: counts.ccd_tickets,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            group,
+            target_user_id,
+            candidates,
+            unmergeable_user_ids,
+        }
+    }
+
+    pub fn tickets_to_move(&self) -> u64 {
+        self.candidates
+            .iter()
+            .map(MergeCandidate::tickets_to_move)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn user(id: u64, email: &str, phone: Option<&str>, role: &str) -> User {
+        serde_json::from_value(json!({
+            "id": id, "name": "User", "email": email, "phone": phone, "role": role
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_group_and_report_duplicates() {
+        let users = vec![
+            user(1, "Ann@Example.com", None, "end-user"),
+            user(2, "ann@example.com", None, "end-user"),
+            user(3, "other@example.com", Some("+1 (555) 010-2000"), "agent"),
+            user(4, "ann.work@example.com", None, "end-user"),
+            user(5, "solo@example.com", None, "end-user"),
+        ];
+        let identities: HashMap<u64, Vec<UserIdentity>> = serde_json::from_value(json!({
+            "2": [{ "type": "phone_number", "value": "15550102000" }],
+            "4": [{ "type": "email", "value": "ann@example.com" }]
+        }))
+        .unwrap();
+
+        let groups = group_duplicate_users(&users, &identities);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].user_ids, vec![1, 2, 3, 4]);
+        assert_eq!(
+            groups[0].shared,
+            vec![
+                DuplicateKey::Email("ann@example.com".to_string()),
+                DuplicateKey::Phone("15550102000".to_string()),
+            ]
+        );
+
+        let related: HashMap<u64, UserRelated> = serde_json::from_value(json!({
+            "1": { "requested_tickets": 4, "ccd_tickets": 1 },
+            "2": { "requested_tickets": 9 }
+        }))
+        .unwrap();
+        let report = DuplicateUserReport::build(groups[0].clone(), &users, &related);
+        assert_eq!(report.target_user_id, Some(2));
+        assert_eq!(report.unmergeable_user_ids, vec![3]);
+        let sources: Vec<u64> = report
+            .candidates
+            .iter()
+            .map(|candidate| candidate.source_user_id)
+            .collect();
+        assert_eq!(sources, vec![1, 4]);
+        assert_eq!(report.tickets_to_move(), 5);
+
+        let staff_only = DuplicateUserGroup {
+            user_ids: vec![3],
+            shared: Vec::new(),
+        };
+        let report = DuplicateUserReport::build(staff_only, &users, &related);
+        assert_eq!(report.target_user_id, None);
+        assert!(report.candidates.is_empty());
+    }
+}
@@ -0,0 +1,503 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::business_rule::{RuleCondition, RuleConditions};
+use crate::models::ticket::{CustomField, TicketPriority, TicketStatus, TicketType};
+use crate::models::ticket_macro::MacroRestriction;
+
+/// Views are restricted to a user or groups the same way macros are
+pub type ViewRestriction = MacroRestriction;
+
+/// Tickets match a view when they meet every `all` condition and at least one `any`
+/// condition, written the same way as trigger and automation conditions
+pub type ViewConditions = RuleConditions;
+
+pub type ViewCondition = RuleCondition;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct View {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    pub title: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction: Option<ViewRestriction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<ViewConditions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution: Option<ViewExecution>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl View {
+    /// The writable parts of this view, e.g. to copy it or redeploy it elsewhere
+    pub fn to_upsert(&self) -> ViewUpsert {
+        let conditions = self.conditions.clone().unwrap_or_default();
+        let output = self.execution.as_ref().map(|execution| ViewOutput {
+            columns: Some(
+                execution
+                    .columns
+                    .iter()
+                    .map(|column| column.id.clone())
+                    .collect(),
+            ),
+            group_by: execution.group_by.clone(),
+            group_order: execution.group_order.clone(),
+            sort_by: execution.sort_by.clone(),
+            sort_order: execution.sort_order.clone(),
+        });
+
+        ViewUpsert {
+            title: Some(self.title.clone()),
+            description: self.description.clone(),
+            active: self.active,
+            position: self.position,
+            restriction: self.restriction.clone(),
+            all: Some(conditions.all),
+            any: Some(conditions.any),
+            output,
+        }
+    }
+}
+
+/// How a view's tickets are grouped, sorted and displayed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewExecution {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_order: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<ViewColumn>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<ViewColumn>,
+
+    #[serde(default)]
+    pub columns: Vec<ViewColumn>,
+
+    #[serde(default)]
+    pub fields: Vec<ViewColumn>,
+
+    #[serde(default)]
+    pub custom_fields: Vec<ViewColumn>,
+}
+
+/// A column is either a standard field such as "subject" or a custom field id
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ViewColumnId {
+    CustomField(u64),
+    Field(String),
+}
+
+impl ViewColumnId {
+    /// Key of this column's value in an executed row
+    pub fn row_key(&self) -> String {
+        match self {
+            ViewColumnId::CustomField(id) => id.to_string(),
+            ViewColumnId::Field(name) => name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewColumn {
+    pub id: ViewColumnId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub column_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filterable: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sortable: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewResponse {
+    pub view: View,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewsResponse {
+    pub views: Vec<View>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+/// Columns, grouping and sorting used when creating a view or previewing conditions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ViewOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<ViewColumnId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_order: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<String>,
+}
+
+impl ViewOutput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn column(mut self, column: ViewColumnId) -> Self {
+        self.columns.get_or_insert_with(Vec::new).push(column);
+        self
+    }
+
+    pub fn group_by(mut self, field: impl Into<String>, order: impl Into<String>) -> Self {
+        self.group_by = Some(field.into());
+        self.group_order = Some(order.into());
+        self
+    }
+
+    pub fn sort_by(mut self, field: impl Into<String>, order: impl Into<String>) -> Self {
+        self.sort_by = Some(field.into());
+        self.sort_order = Some(order.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewRequest {
+    pub view: ViewUpsert,
+}
+
+/// Body for creating or updating a view; only set fields are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ViewUpsert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction: Option<ViewRestriction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<Vec<ViewCondition>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub any: Option<Vec<ViewCondition>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<ViewOutput>,
+}
+
+impl ViewUpsert {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn restriction(mut self, restriction: ViewRestriction) -> Self {
+        self.restriction = Some(restriction);
+        self
+    }
+
+    pub fn all(mut self, condition: ViewCondition) -> Self {
+        self.all.get_or_insert_with(Vec::new).push(condition);
+        self
+    }
+
+    pub fn any(mut self, condition: ViewCondition) -> Self {
+        self.any.get_or_insert_with(Vec::new).push(condition);
+        self
+    }
+
+    pub fn output(mut self, output: ViewOutput) -> Self {
+        self.output = Some(output);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewPreviewRequest {
+    pub view: ViewPreview,
+}
+
+/// Ad-hoc conditions to run as if they were a saved view
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ViewPreview {
+    #[serde(default)]
+    pub all: Vec<ViewCondition>,
+
+    #[serde(default)]
+    pub any: Vec<ViewCondition>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<ViewOutput>,
+}
+
+impl ViewPreview {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn all(mut self, condition: ViewCondition) -> Self {
+        self.all.push(condition);
+        self
+    }
+
+    pub fn any(mut self, condition: ViewCondition) -> Self {
+        self.any.push(condition);
+        self
+    }
+
+    pub fn output(mut self, output: ViewOutput) -> Self {
+        self.output = Some(output);
+        self
+    }
+}
+
+/// Result of executing or previewing a view: the displayed columns and one row per ticket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewExecuteResponse {
+    #[serde(default)]
+    pub columns: Vec<ViewColumn>,
+
+    #[serde(default)]
+    pub rows: Vec<ViewRow>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+impl ViewExecuteResponse {
+    pub fn ticket_ids(&self) -> Vec<u64> {
+        self.rows.iter().filter_map(ViewRow::ticket_id).collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewRow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<ViewRowTicket>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    /// Column values keyed by column id
+    #[serde(flatten)]
+    pub values: HashMap<String, serde_json::Value>,
+}
+
+impl ViewRow {
+    pub fn ticket_id(&self) -> Option<u64> {
+        self.ticket.as_ref().map(|ticket| ticket.id).or_else(|| {
+            self.values
+                .get("ticket_id")
+                .and_then(serde_json::Value::as_u64)
+        })
+    }
+
+    /// The value shown in `column`, looking custom fields up by id
+    pub fn value(&self, column: &ViewColumn) -> Option<&serde_json::Value> {
+        if let ViewColumnId::CustomField(id) = column.id
+            && let Some(field) = self
+                .custom_fields
+                .as_ref()
+                .and_then(|fields| fields.iter().find(|field| field.id == id))
+        {
+            return Some(&field.value);
+        }
+        self.values.get(&column.id.row_key())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewRowTicket {
+    pub id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TicketStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TicketPriority>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub ticket_type: Option<TicketType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewCount {
+    pub view_id: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretty: Option<String>,
+
+    /// False while Zendesk is still computing the count; poll again later
+    #[serde(default)]
+    pub fresh: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewCountsResponse {
+    pub view_counts: Vec<ViewCount>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_execute_rows() {
+        let response: ViewExecuteResponse = serde_json::from_value(json!({
+            "columns": [
+                { "id": "subject", "title": "Subject" },
+                { "id": 360001, "title": "Plan" }
+            ],
+            "rows": [
+                {
+                    "ticket": { "id": 101, "subject": "Refund", "status": "open" },
+                    "subject": "Refund",
+                    "requester_id": 7,
+                    "custom_fields": [{ "id": 360001, "value": "enterprise" }]
+                },
+                { "ticket_id": 102, "subject": "Login" }
+            ],
+            "next_page": "https://example.zendesk.com/api/v2/views/1/execute.json?page=2",
+            "count": 150
+        }))
+        .unwrap();
+
+        assert_eq!(response.ticket_ids(), vec![101, 102]);
+        assert_eq!(response.columns[1].id, ViewColumnId::CustomField(360001));
+        let row = &response.rows[0];
+        assert_eq!(row.value(&response.columns[0]), Some(&json!("Refund")));
+        assert_eq!(row.value(&response.columns[1]), Some(&json!("enterprise")));
+    }
+
+    #[test]
+    fn test_upsert_serialize() {
+        let view = ViewUpsert::new()
+            .title("Escalations")
+            .all(ViewCondition::new("status", "less_than", "solved"))
+            .any(ViewCondition::new("current_tags", "includes", "vip"))
+            .output(
+                ViewOutput::new()
+                    .column(ViewColumnId::Field("subject".to_string()))
+                    .column(ViewColumnId::CustomField(360001))
+                    .sort_by("created_at", "desc"),
+            );
+
+        let value = serde_json::to_value(ViewRequest { view }).unwrap();
+        assert_eq!(value["view"]["all"][0]["operator"], "less_than");
+        assert_eq!(
+            value["view"]["output"]["columns"],
+            json!(["subject", 360001])
+        );
+        assert!(value["view"].get("active").is_none());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod pagination;
+#[allow(clippy::module_inception)]
 pub mod query;
 pub mod sideloading;
 
@@ -56,10 +56,10 @@ impl QueryParams {
     pub fn to_query_string(&self) -> String {
         let mut params = Vec::new();
 
-        if let Some(ref include) = self.include {
-            if !include.is_empty() {
-                params.push(format!("include={}", include.join(",")));
-            }
+        if let Some(ref include) = self.include
+            && !include.is_empty()
+        {
+            params.push(format!("include={}", include.join(",")));
         }
 
         if let Some(page) = self.page {
@@ -0,0 +1,274 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::client::ZendeskClient;
+use crate::errors::{Result, ZendeskError};
+use crate::sync::desired::DesiredState;
+use crate::sync::plan::{ChangeAction, CurrentState, PlannedChange, ResourceKind, SyncPlan};
+
+/// Counts of the changes made by [`ZendeskClient::apply_sync_plan`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    pub created: usize,
+    pub updated: usize,
+    pub deleted: usize,
+}
+
+impl ZendeskClient {
+    /// Fetch the sections of the account that `desired` manages
+    pub async fn fetch_sync_state(&self, desired: &DesiredState) -> Result<CurrentState> {
+        let mut current = CurrentState::default();
+        // Triggers name their categories, so categories are needed for either section
+        if desired.trigger_categories.is_some() || desired.triggers.is_some() {
+            current.trigger_categories = self.list_trigger_categories().await?;
+        }
+        if desired.triggers.is_some() {
+            current.triggers = self.list_all_triggers().await?;
+        }
+        if desired.automations.is_some() {
+            current.automations = self.list_all_automations().await?;
+        }
+        if desired.macros.is_some() {
+            current.macros = self.list_macros().await?;
+        }
+        if desired.views.is_some() {
+            current.views = self.list_all_views().await?;
+        }
+        Ok(current)
+    }
+
+    /// Compare `desired` with the account and return the changes needed, without applying them
+    pub async fn plan_sync(&self, desired: &DesiredState) -> Result<SyncPlan> {
+        let current = self.fetch_sync_state(desired).await?;
+        SyncPlan::build(desired, &current)
+    }
+
+    /// Apply a plan in order. Stops at the first failing change with a
+    /// [`ZendeskError::Sync`] holding the report of what was applied before it; those
+    /// changes are kept, so planning again shows what is left.
+    pub async fn apply_sync_plan(&self, plan: &SyncPlan) -> Result<SyncReport> {
+        let mut report = SyncReport::default();
+        let mut category_ids: HashMap<String, String> = HashMap::new();
+        if plan.changes.iter().any(|change| change.category.is_some()) {
+            for category in self.list_trigger_categories().await? {
+                if let Some(id) = category.id {
+                    category_ids.insert(category.name, id);
+                }
+            }
+        }
+
+        for change in &plan.changes {
+            if let Err(source) = self.apply_change(change, &mut category_ids).await {
+                return Err(ZendeskError::Sync {
+                    report,
+                    source: Box::new(source),
+                });
+            }
+            match change.action {
+                ChangeAction::Create => report.created += 1,
+                ChangeAction::Update => report.updated += 1,
+                ChangeAction::Delete => report.deleted += 1,
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn apply_change(
+        &self,
+        change: &PlannedChange,
+        category_ids: &mut HashMap<String, String>,
+    ) -> Result<()> {
+        if change.action == ChangeAction::Delete {
+            let id = change_id(change)?;
+            match change.kind {
+                ResourceKind::TriggerCategory => self.delete_trigger_category(id).await?,
+                ResourceKind::Trigger => self.delete_trigger(numeric_id(change)?).await?,
+                ResourceKind::Automation => self.delete_automation(numeric_id(change)?).await?,
+                ResourceKind::Macro => self.delete_macro(numeric_id(change)?).await?,
+                ResourceKind::View => self.delete_view(numeric_id(change)?).await?,
+            }
+            return Ok(());
+        }
+
+        let mut body = change.body.clone();
+        if let Some(name) = &change.category {
+            let id = category_ids.get(name).ok_or_else(|| {
+                ZendeskError::validation(format!(
+                    "trigger \"{}\" uses unknown category \"{}\"",
+                    change.title, name
+                ))
+            })?;
+            body["category_id"] = Value::from(id.as_str());
+        }
+        let create = change.action == ChangeAction::Create;
+
+        match change.kind {
+            ResourceKind::TriggerCategory => {
+                let category = if create {
+                    self.create_trigger_category(from_body(body)?).await?
+                } else {
+                    self.update_trigger_category(change_id(change)?, from_body(body)?)
+                        .await?
+                };
+                if let Some(id) = category.id {
+                    category_ids.insert(category.name, id);
+                }
+            }
+            ResourceKind::Trigger if create => {
+                self.create_trigger(from_body(body)?).await?;
+            }
+            ResourceKind::Trigger => {
+                self.update_trigger(numeric_id(change)?, from_body(body)?)
+                    .await?;
+            }
+            ResourceKind::Automation if create => {
+                self.create_automation(from_body(body)?).await?;
+            }
+            ResourceKind::Automation => {
+                self.update_automation(numeric_id(change)?, from_body(body)?)
+                    .await?;
+            }
+            ResourceKind::Macro if create => {
+                self.create_macro(from_body(body)?).await?;
+            }
+            ResourceKind::Macro => {
+                self.update_macro(numeric_id(change)?, from_body(body)?)
+                    .await?;
+            }
+            ResourceKind::View if create => {
+                self.create_view(from_body(body)?).await?;
+            }
+            ResourceKind::View => {
+                self.update_view(numeric_id(change)?, from_body(body)?)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn from_body<T: DeserializeOwned>(body: Value) -> Result<T> {
+    Ok(serde_json::from_value(body)?)
+}
+
+fn change_id(change: &PlannedChange) -> Result<&str> {
+    change.id.as_deref().ok_or_else(|| {
+        ZendeskError::UnexpectedResponse(format!(
+            "{} \"{}\" has no id in the account",
+            change.kind, change.title
+        ))
+    })
+}
+
+fn numeric_id(change: &PlannedChange) -> Result<u64> {
+    let id = change_id(change)?;
+    id.parse().map_err(|_| {
+        ZendeskError::UnexpectedResponse(format!(
+            "{} \"{}\" has a non-numeric id {}",
+            change.kind, change.title, id
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthMethod;
+    use crate::client::mock_client;
+    use crate::config::ZendeskConfig;
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_apply_creates_updates_and_deletes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/macros.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "macros": [
+                    { "id": 1, "title": "Close", "actions": [{ "field": "status", "value": "open" }] },
+                    { "id": 2, "title": "Retired", "actions": [] }
+                ],
+                "next_page": null
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v2/macros/1.json"))
+            .and(body_partial_json(json!({
+                "macro": { "actions": [{ "field": "status", "value": "solved" }] }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "macro": { "id": 1, "title": "Close", "actions": [{ "field": "status", "value": "solved" }] }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v2/macros.json"))
+            .and(body_partial_json(json!({ "macro": { "title": "Escalate" } })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "macro": { "id": 3, "title": "Escalate", "actions": [{ "field": "priority", "value": "urgent" }] }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v2/macros/2.json"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let desired = DesiredState::from_json_str(
+            r#"{
+                "prune": true,
+                "macros": [
+                    { "title": "Close", "actions": [{ "field": "status", "value": "solved" }] },
+                    { "title": "Escalate", "actions": [{ "field": "priority", "value": "urgent" }] }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let plan = client.plan_sync(&desired).await.unwrap();
+        assert_eq!(plan.count(ChangeAction::Delete), 1);
+
+        let report = client.apply_sync_plan(&plan).await.unwrap();
+        assert_eq!(
+            report,
+            SyncReport {
+                created: 1,
+                updated: 1,
+                deleted: 1
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_change_is_not_counted() {
+        let config = ZendeskConfig::new("example", AuthMethod::api_token("a@example.com", "t"));
+        let client = ZendeskClient::new(config).unwrap();
+        let plan = SyncPlan {
+            changes: vec![PlannedChange {
+                kind: ResourceKind::Trigger,
+                action: ChangeAction::Delete,
+                title: "Old rule".to_string(),
+                id: Some("not-a-number".to_string()),
+                body: Value::Null,
+                category: None,
+                fields: Vec::new(),
+            }],
+        };
+
+        let error = client.apply_sync_plan(&plan).await.unwrap_err();
+        assert_eq!(error.sync_report(), Some(SyncReport::default()));
+        assert!(matches!(
+            error,
+            ZendeskError::Sync { source, .. } if matches!(*source, ZendeskError::UnexpectedResponse(_))
+        ));
+    }
+}
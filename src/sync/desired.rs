@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::errors::{Result, ZendeskError};
+use crate::models::automation::AutomationUpsert;
+use crate::models::ticket_macro::MacroUpsert;
+use crate::models::trigger::{TriggerCategoryUpsert, TriggerUpsert};
+use crate::models::view::ViewUpsert;
+
+/// Business rules as they should exist in the account, read from JSON or YAML files.
+///
+/// Resources are matched to the account by title (by name for trigger categories).
+/// A section that is left out is not managed at all; with `prune` set, resources of a
+/// managed section that are missing from the files are deleted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DesiredState {
+    #[serde(default)]
+    pub prune: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_categories: Option<Vec<TriggerCategoryUpsert>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub triggers: Option<Vec<DesiredTrigger>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automations: Option<Vec<AutomationUpsert>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub macros: Option<Vec<MacroUpsert>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub views: Option<Vec<ViewUpsert>>,
+}
+
+/// A trigger that may name its category instead of giving its id, so it can refer to a
+/// category created in the same run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DesiredTrigger {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    #[serde(flatten)]
+    pub trigger: TriggerUpsert,
+}
+
+impl DesiredState {
+    pub fn from_json_str(source: &str) -> Result<Self> {
+        Ok(serde_json::from_str(source)?)
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_str(source: &str) -> Result<Self> {
+        serde_yaml::from_str(source)
+            .map_err(|e| ZendeskError::config(format!("invalid YAML: {}", e)))
+    }
+
+    /// Read one file; `.yaml` and `.yml` files are parsed as YAML (with the `yaml` feature),
+    /// anything else as JSON
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+        let is_yaml = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| matches!(extension, "yaml" | "yml"));
+
+        let state = if is_yaml {
+            Self::parse_yaml(&source)
+        } else {
+            Self::from_json_str(&source)
+        };
+        state.map_err(|e| ZendeskError::config(format!("{}: {}", path.display(), e)))
+    }
+
+    #[cfg(feature = "yaml")]
+    fn parse_yaml(source: &str) -> Result<Self> {
+        Self::from_yaml_str(source)
+    }
+
+    #[cfg(not(feature = "yaml"))]
+    fn parse_yaml(_source: &str) -> Result<Self> {
+        Err(ZendeskError::config(
+            "YAML files need the `yaml` feature; enable it or use JSON",
+        ))
+    }
+
+    /// Read and merge several files, e.g. one per resource type
+    pub fn from_files<P: AsRef<Path>>(paths: &[P]) -> Result<Self> {
+        let mut state = Self::default();
+        for path in paths {
+            state.merge(Self::from_file(path)?);
+        }
+        state.validate()?;
+        Ok(state)
+    }
+
+    pub fn merge(&mut self, other: DesiredState) {
+        fn extend<T>(target: &mut Option<Vec<T>>, source: Option<Vec<T>>) {
+            if let Some(items) = source {
+                target.get_or_insert_with(Vec::new).extend(items);
+            }
+        }
+
+        self.prune |= other.prune;
+        extend(&mut self.trigger_categories, other.trigger_categories);
+        extend(&mut self.triggers, other.triggers);
+        extend(&mut self.automations, other.automations);
+        extend(&mut self.macros, other.macros);
+        extend(&mut self.views, other.views);
+    }
+
+    /// Every resource needs a title (a name for categories) that is unique within its section
+    pub fn validate(&self) -> Result<()> {
+        fn check<'a>(
+            section: &str,
+            titles: impl IntoIterator<Item = Option<&'a String>>,
+        ) -> Result<()> {
+            let mut seen = HashSet::new();
+            for (index, title) in titles.into_iter().enumerate() {
+                let title = title.ok_or_else(|| {
+                    ZendeskError::validation(format!("{}[{}] has no title", section, index))
+                })?;
+                if !seen.insert(title) {
+                    return Err(ZendeskError::validation(format!(
+                        "{} contains \"{}\" more than once",
+                        section, title
+                    )));
+                }
+            }
+            Ok(())
+        }
+
+        let categories = self.trigger_categories.iter().flatten();
+        check("trigger_categories", categories.map(|c| c.name.as_ref()))?;
+        let triggers = self.triggers.iter().flatten();
+        check("triggers", triggers.map(|t| t.trigger.title.as_ref()))?;
+        let automations = self.automations.iter().flatten();
+        check("automations", automations.map(|a| a.title.as_ref()))?;
+        check(
+            "macros",
+            self.macros.iter().flatten().map(|m| m.title.as_ref()),
+        )?;
+        check(
+            "views",
+            self.views.iter().flatten().map(|v| v.title.as_ref()),
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "yaml")]
+    use crate::models::business_rule::RuleCondition;
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_and_merge() {
+        let yaml = r#"
+prune: true
+trigger_categories:
+  - name: Notifications
+triggers:
+  - title: Notify requester
+    category: Notifications
+    conditions:
+      all:
+        - { field: update_type, operator: is, value: Create }
+    actions:
+      - { field: notification_user, value: [requester_id, Received, We got it] }
+"#;
+        let json = r#"{ "macros": [{ "title": "Close", "actions": [{ "field": "status", "value": "solved" }] }] }"#;
+
+        let mut state = DesiredState::from_yaml_str(yaml).unwrap();
+        state.merge(DesiredState::from_json_str(json).unwrap());
+        state.validate().unwrap();
+
+        assert!(state.prune);
+        assert!(state.views.is_none());
+        let trigger = &state.triggers.as_ref().unwrap()[0];
+        assert_eq!(trigger.category.as_deref(), Some("Notifications"));
+        assert!(matches!(
+            trigger.trigger.conditions.as_ref().unwrap().all[0],
+            RuleCondition::UpdateType { .. }
+        ));
+        assert_eq!(state.macros.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_validate_duplicate_titles() {
+        let state = DesiredState::from_json_str(
+            r#"{ "views": [{ "title": "Mine" }, { "title": "Mine" }] }"#,
+        )
+        .unwrap();
+        assert!(state.validate().is_err());
+    }
+}
@@ -0,0 +1,9 @@
+//! Declarative sync of business rules: load the desired triggers, automations, macros and
+//! views from files, plan the changes against the account and apply them.
+pub mod apply;
+pub mod desired;
+pub mod plan;
+
+pub use apply::*;
+pub use desired::*;
+pub use plan::*;
@@ -0,0 +1,578 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::errors::{Result, ZendeskError};
+use crate::models::automation::Automation;
+use crate::models::ticket_macro::Macro;
+use crate::models::trigger::{Trigger, TriggerCategory};
+use crate::models::view::View;
+use crate::sync::desired::DesiredState;
+
+/// The business rules currently in the account
+#[derive(Debug, Clone, Default)]
+pub struct CurrentState {
+    pub trigger_categories: Vec<TriggerCategory>,
+    pub triggers: Vec<Trigger>,
+    pub automations: Vec<Automation>,
+    pub macros: Vec<Macro>,
+    pub views: Vec<View>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ResourceKind {
+    TriggerCategory,
+    Trigger,
+    Automation,
+    Macro,
+    View,
+}
+
+impl fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceKind::TriggerCategory => write!(f, "trigger category"),
+            ResourceKind::Trigger => write!(f, "trigger"),
+            ResourceKind::Automation => write!(f, "automation"),
+            ResourceKind::Macro => write!(f, "macro"),
+            ResourceKind::View => write!(f, "view"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// One top-level field whose value differs. Only fields present in the desired resource
+/// are compared: a field left out of the files is not managed and keeps its current value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    /// `None` when the field is not set in the account
+    pub current: Option<Value>,
+    pub desired: Value,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannedChange {
+    pub kind: ResourceKind,
+    pub action: ChangeAction,
+    pub title: String,
+    /// Id of the existing resource, for updates and deletes
+    pub id: Option<String>,
+    /// The create or update body as JSON
+    pub body: Value,
+    /// Category name of a trigger, resolved to an id when the plan is applied
+    pub category: Option<String>,
+    pub fields: Vec<FieldChange>,
+}
+
+/// Changes that bring the account in line with a [`DesiredState`], in the order they must
+/// be applied: categories are created before the triggers that use them and deleted after.
+#[derive(Debug, Clone, Default)]
+pub struct SyncPlan {
+    pub changes: Vec<PlannedChange>,
+}
+
+impl SyncPlan {
+    pub fn build(desired: &DesiredState, current: &CurrentState) -> Result<Self> {
+        desired.validate()?;
+        let mut upserts = Vec::new();
+        let mut deletes = Vec::new();
+
+        if let Some(categories) = &desired.trigger_categories {
+            let existing = current
+                .trigger_categories
+                .iter()
+                .map(|category| (category.name.clone(), category.id.clone(), category))
+                .collect::<Vec<_>>();
+            diff_section(
+                ResourceKind::TriggerCategory,
+                categories
+                    .iter()
+                    .map(|category| (category.name.clone(), category)),
+                &existing,
+                desired.prune,
+                &mut upserts,
+                &mut deletes,
+            )?;
+        }
+
+        if let Some(triggers) = &desired.triggers {
+            let category_ids: HashMap<&str, &str> = current
+                .trigger_categories
+                .iter()
+                .filter_map(|category| Some((category.name.as_str(), category.id.as_deref()?)))
+                .collect();
+            check_trigger_categories(desired, current)?;
+            let existing = current
+                .triggers
+                .iter()
+                .map(|trigger| {
+                    let id = trigger.id.map(|id| id.to_string());
+                    (trigger.title.clone(), id, trigger.to_upsert())
+                })
+                .collect::<Vec<_>>();
+            let start = upserts.len();
+            diff_section(
+                ResourceKind::Trigger,
+                triggers
+                    .iter()
+                    .map(|trigger| (trigger.trigger.title.clone(), &trigger.trigger)),
+                &existing,
+                desired.prune,
+                &mut upserts,
+                &mut deletes,
+            )?;
+
+            // Category names are compared through the ids of existing categories; a
+            // category that is only created by this plan always counts as a change
+            let categories: HashMap<&str, &str> = triggers
+                .iter()
+                .filter_map(|trigger| {
+                    Some((
+                        trigger.trigger.title.as_deref()?,
+                        trigger.category.as_deref()?,
+                    ))
+                })
+                .collect();
+            let current_bodies: HashMap<&str, Value> = existing
+                .iter()
+                .map(|(title, _, upsert)| (title.as_str(), to_object(upsert)))
+                .collect();
+            for change in upserts.iter_mut().skip(start) {
+                let Some(name) = categories.get(change.title.as_str()) else {
+                    continue;
+                };
+                change.category = Some(name.to_string());
+                let current = current_bodies
+                    .get(change.title.as_str())
+                    .and_then(|body| body.get("category_id"))
+                    .cloned();
+                let resolved = category_ids.get(name).map(|id| Value::from(*id));
+                if current.is_none() || current != resolved {
+                    change.fields.push(FieldChange {
+                        field: "category".to_string(),
+                        current,
+                        desired: Value::from(*name),
+                    });
+                }
+            }
+            upserts.retain(|change| {
+                change.action != ChangeAction::Update || !change.fields.is_empty()
+            });
+        }
+
+        if let Some(automations) = &desired.automations {
+            let existing = current
+                .automations
+                .iter()
+                .map(|automation| {
+                    let id = automation.id.map(|id| id.to_string());
+                    (automation.title.clone(), id, automation.to_upsert())
+                })
+                .collect::<Vec<_>>();
+            diff_section(
+                ResourceKind::Automation,
+                automations
+                    .iter()
+                    .map(|automation| (automation.title.clone(), automation)),
+                &existing,
+                desired.prune,
+                &mut upserts,
+                &mut deletes,
+            )?;
+        }
+
+        if let Some(macros) = &desired.macros {
+            let existing = current
+                .macros
+                .iter()
+                .map(|ticket_macro| {
+                    let id = ticket_macro.id.map(|id| id.to_string());
+                    (ticket_macro.title.clone(), id, ticket_macro.to_upsert())
+                })
+                .collect::<Vec<_>>();
+            diff_section(
+                ResourceKind::Macro,
+                macros
+                    .iter()
+                    .map(|ticket_macro| (ticket_macro.title.clone(), ticket_macro)),
+                &existing,
+                desired.prune,
+                &mut upserts,
+                &mut deletes,
+            )?;
+        }
+
+        if let Some(views) = &desired.views {
+            let existing = current
+                .views
+                .iter()
+                .map(|view| {
+                    let id = view.id.map(|id| id.to_string());
+                    (view.title.clone(), id, view.to_upsert())
+                })
+                .collect::<Vec<_>>();
+            diff_section(
+                ResourceKind::View,
+                views.iter().map(|view| (view.title.clone(), view)),
+                &existing,
+                desired.prune,
+                &mut upserts,
+                &mut deletes,
+            )?;
+        }
+
+        // Rules are deleted before the categories that may still contain them
+        deletes.sort_by_key(|change| change.kind == ResourceKind::TriggerCategory);
+        upserts.append(&mut deletes);
+        Ok(Self { changes: upserts })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn count(&self, action: ChangeAction) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.action == action)
+            .count()
+    }
+}
+
+/// Every category a trigger names must exist after the plan runs: either it is in the
+/// files or it is already in the account and not pruned
+fn check_trigger_categories(desired: &DesiredState, current: &CurrentState) -> Result<()> {
+    let mut known: HashSet<&str> = HashSet::new();
+    match &desired.trigger_categories {
+        Some(categories) => {
+            known.extend(
+                categories
+                    .iter()
+                    .filter_map(|category| category.name.as_deref()),
+            );
+            if !desired.prune {
+                known.extend(current.trigger_categories.iter().map(|c| c.name.as_str()));
+            }
+        }
+        None => known.extend(current.trigger_categories.iter().map(|c| c.name.as_str())),
+    }
+
+    for trigger in desired.triggers.iter().flatten() {
+        let Some(name) = trigger.category.as_deref() else {
+            continue;
+        };
+        if !known.contains(name) {
+            return Err(ZendeskError::validation(format!(
+                "trigger \"{}\" uses unknown category \"{}\"",
+                trigger.trigger.title.as_deref().unwrap_or_default(),
+                name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Compare one section of the desired state with the matching resources in the account
+fn diff_section<'a, D, C>(
+    kind: ResourceKind,
+    desired: impl Iterator<Item = (Option<String>, &'a D)>,
+    existing: &[(String, Option<String>, C)],
+    prune: bool,
+    upserts: &mut Vec<PlannedChange>,
+    deletes: &mut Vec<PlannedChange>,
+) -> Result<()>
+where
+    D: Serialize + 'a,
+    C: Serialize,
+{
+    let mut wanted = HashSet::new();
+    for (title, item) in desired {
+        // `DesiredState::validate` guarantees a title
+        let title = title.unwrap_or_default();
+        let body = to_object(item);
+        let mut matches = existing.iter().filter(|(name, _, _)| *name == title);
+        let found = matches.next();
+        if matches.next().is_some() {
+            return Err(ZendeskError::validation(format!(
+                "the account has more than one {} titled \"{}\"",
+                kind, title
+            )));
+        }
+
+        let change = match found {
+            None => {
+                let fields = fields_of(&body)
+                    .map(|(field, value)| FieldChange {
+                        field: field.clone(),
+                        current: None,
+                        desired: value.clone(),
+                    })
+                    .collect();
+                PlannedChange {
+                    kind,
+                    action: ChangeAction::Create,
+                    title: title.clone(),
+                    id: None,
+                    body,
+                    category: None,
+                    fields,
+                }
+            }
+            Some((_, id, current)) => {
+                let current = to_object(current);
+                let fields = fields_of(&body)
+                    .filter(|(field, value)| current.get(field.as_str()) != Some(*value))
+                    .map(|(field, value)| FieldChange {
+                        field: field.clone(),
+                        current: current.get(field.as_str()).cloned(),
+                        desired: value.clone(),
+                    })
+                    .collect();
+                PlannedChange {
+                    kind,
+                    action: ChangeAction::Update,
+                    title: title.clone(),
+                    id: id.clone(),
+                    body,
+                    category: None,
+                    fields,
+                }
+            }
+        };
+
+        // Triggers keep unchanged updates until their category has been compared
+        if change.action == ChangeAction::Create
+            || !change.fields.is_empty()
+            || kind == ResourceKind::Trigger
+        {
+            upserts.push(change);
+        }
+        wanted.insert(title);
+    }
+
+    if prune {
+        for (title, id, _) in existing {
+            if !wanted.contains(title) {
+                deletes.push(PlannedChange {
+                    kind,
+                    action: ChangeAction::Delete,
+                    title: title.clone(),
+                    id: id.clone(),
+                    body: Value::Null,
+                    category: None,
+                    fields: Vec::new(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn to_object(value: &impl Serialize) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+fn fields_of(body: &Value) -> impl Iterator<Item = (&String, &Value)> {
+    body.as_object().into_iter().flatten()
+}
+
+fn compact(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Prints the plan as a diff: `+` creates, `~` updates, `-` deletes
+impl fmt::Display for SyncPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+
+        for change in &self.changes {
+            let marker = match change.action {
+                ChangeAction::Create => '+',
+                ChangeAction::Update => '~',
+                ChangeAction::Delete => '-',
+            };
+            write!(f, "{} {} \"{}\"", marker, change.kind, change.title)?;
+            if let Some(id) = &change.id {
+                write!(f, " ({})", id)?;
+            }
+            writeln!(f)?;
+
+            for field in &change.fields {
+                match &field.current {
+                    Some(current) => writeln!(
+                        f,
+                        "    {}: {} -> {}",
+                        field.field,
+                        compact(current),
+                        compact(&field.desired)
+                    )?,
+                    None => writeln!(f, "    {}: {}", field.field, compact(&field.desired))?,
+                }
+            }
+        }
+
+        writeln!(
+            f,
+            "\nPlan: {} to create, {} to update, {} to delete.",
+            self.count(ChangeAction::Create),
+            self.count(ChangeAction::Update),
+            self.count(ChangeAction::Delete)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn current() -> CurrentState {
+        CurrentState {
+            trigger_categories: serde_json::from_value(json!([
+                { "id": "10026", "name": "Notifications", "position": 1 },
+                { "id": "10027", "name": "Legacy", "position": 2 }
+            ]))
+            .unwrap(),
+            triggers: serde_json::from_value(json!([
+                {
+                    "id": 25,
+                    "title": "Notify requester",
+                    "active": true,
+                    "category_id": "10026",
+                    "conditions": { "all": [{ "field": "update_type", "operator": "is", "value": "Create" }], "any": [] },
+                    "actions": [{ "field": "status", "value": "open" }]
+                },
+                {
+                    "id": 26,
+                    "title": "Old rule",
+                    "category_id": "10027",
+                    "conditions": { "all": [], "any": [] },
+                    "actions": []
+                }
+            ]))
+            .unwrap(),
+            macros: serde_json::from_value(json!([
+                { "id": 7, "title": "Close", "actions": [{ "field": "status", "value": "solved" }] }
+            ]))
+            .unwrap(),
+            ..CurrentState::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_orders_and_diffs() {
+        let desired = DesiredState::from_json_str(
+            r#"{
+                "prune": true,
+                "trigger_categories": [{ "name": "Notifications" }, { "name": "Escalations" }],
+                "triggers": [
+                    {
+                        "title": "Notify requester",
+                        "category": "Notifications",
+                        "conditions": { "all": [{ "field": "update_type", "operator": "is", "value": "Create" }], "any": [] },
+                        "actions": [{ "field": "status", "value": "pending" }]
+                    },
+                    { "title": "Escalate", "category": "Escalations", "actions": [] }
+                ],
+                "macros": [{ "title": "Close", "actions": [{ "field": "status", "value": "solved" }] }]
+            }"#,
+        )
+        .unwrap();
+
+        let plan = SyncPlan::build(&desired, &current()).unwrap();
+        let summary: Vec<_> = plan
+            .changes
+            .iter()
+            .map(|change| (change.kind, change.action, change.title.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    ResourceKind::TriggerCategory,
+                    ChangeAction::Create,
+                    "Escalations"
+                ),
+                (
+                    ResourceKind::Trigger,
+                    ChangeAction::Update,
+                    "Notify requester"
+                ),
+                (ResourceKind::Trigger, ChangeAction::Create, "Escalate"),
+                (ResourceKind::Trigger, ChangeAction::Delete, "Old rule"),
+                (
+                    ResourceKind::TriggerCategory,
+                    ChangeAction::Delete,
+                    "Legacy"
+                ),
+            ]
+        );
+
+        let update = &plan.changes[1];
+        assert_eq!(update.id.as_deref(), Some("25"));
+        assert_eq!(update.fields.len(), 1);
+        assert_eq!(update.fields[0].field, "actions");
+        assert_eq!(plan.changes[2].category.as_deref(), Some("Escalations"));
+
+        let printed = plan.to_string();
+        assert!(printed.contains("~ trigger \"Notify requester\" (25)"));
+        assert!(printed.contains("Plan: 2 to create, 1 to update, 2 to delete."));
+    }
+
+    #[test]
+    fn test_omitted_fields_are_unmanaged() {
+        let desired = DesiredState::from_json_str(
+            r#"{ "macros": [{ "title": "Close", "description": "Solve the ticket" }] }"#,
+        )
+        .unwrap();
+        let plan = SyncPlan::build(&desired, &current()).unwrap();
+        assert_eq!(plan.changes.len(), 1);
+        assert_eq!(
+            plan.changes[0].fields,
+            vec![FieldChange {
+                field: "description".to_string(),
+                current: None,
+                desired: json!("Solve the ticket"),
+            }]
+        );
+        assert!(plan.changes[0].body.get("actions").is_none());
+    }
+
+    #[test]
+    fn test_unknown_trigger_category_is_rejected() {
+        let desired = DesiredState::from_json_str(
+            r#"{ "triggers": [{ "title": "Escalate", "category": "Escalations", "actions": [] }] }"#,
+        )
+        .unwrap();
+        assert!(SyncPlan::build(&desired, &current()).is_err());
+
+        // A pruned category no longer exists once the plan has run
+        let desired = DesiredState::from_json_str(
+            r#"{
+                "prune": true,
+                "trigger_categories": [{ "name": "Notifications" }],
+                "triggers": [{ "title": "Old rule", "category": "Legacy", "actions": [] }]
+            }"#,
+        )
+        .unwrap();
+        assert!(SyncPlan::build(&desired, &current()).is_err());
+    }
+
+    #[test]
+    fn test_unmanaged_sections_are_untouched() {
+        let desired = DesiredState::from_json_str(r#"{ "prune": true, "views": [] }"#).unwrap();
+        let plan = SyncPlan::build(&desired, &current()).unwrap();
+        assert!(plan.is_empty());
+        assert_eq!(plan.to_string(), "No changes.\n");
+    }
+}